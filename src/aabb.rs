@@ -1,4 +1,9 @@
+use std::rc::Rc;
+
 use crate::math_utils::{Vector3, Matrix3, Quaternion};
+use crate::math_utils::math_utils::calculate_world_inertia_tensor;
+use crate::mesh::TriangleMesh;
+use crate::convex::ConvexHull;
 
 /// Enumerates the collision shape types.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -6,6 +11,57 @@ pub enum CollisionShape {
     Sphere,
     AABB,
     OBB,
+    /// Static triangle-mesh geometry (level/terrain); see [`RigidBody::mesh`]
+    TriangleMesh,
+    /// Arbitrary convex geometry resolved via GJK/EPA; see [`RigidBody::convex_hull`]
+    ConvexHull,
+}
+
+/// Collision filtering bitmasks, the standard layer/mask scheme for trigger
+/// volumes, sensor-only bodies, and faction-based collision. Two bodies generate
+/// contacts only when each one's `membership` intersects the other's `filter`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CollisionGroups {
+    pub membership: u32,
+    pub filter: u32,
+}
+
+impl CollisionGroups {
+    pub fn new(membership: u32, filter: u32) -> Self {
+        Self { membership, filter }
+    }
+
+    /// The default groups: a member of every layer, and collides with every layer
+    pub fn all() -> Self {
+        Self { membership: u32::MAX, filter: u32::MAX }
+    }
+
+    /// A member of no layer and collides with nothing; useful as a starting point
+    /// before opting into specific layers
+    pub fn none() -> Self {
+        Self { membership: 0, filter: 0 }
+    }
+
+    /// A member of only `layer` (a single bit, e.g. `1 << 2`), colliding with every layer
+    pub fn member_of(layer: u32) -> Self {
+        Self { membership: layer, filter: u32::MAX }
+    }
+
+    /// A member of every layer, but only colliding with bodies in `mask`
+    pub fn only_collides_with(mask: u32) -> Self {
+        Self { membership: u32::MAX, filter: mask }
+    }
+
+    /// Whether a body in this group should generate contacts with a body in `other`
+    pub fn collides_with(&self, other: &CollisionGroups) -> bool {
+        (self.membership & other.filter) != 0 && (other.membership & self.filter) != 0
+    }
+}
+
+impl Default for CollisionGroups {
+    fn default() -> Self {
+        Self::all()
+    }
 }
 
 /// Represents an Axis-Aligned Bounding Box.
@@ -129,10 +185,18 @@ pub struct RigidBody {
     pub shape: CollisionShape,     // Collision shape type
     pub radius: f32,              // Collision radius for sphere collisions
     pub half_extents: Vector3,    // Half-dimensions for AABB/OBB collisions
+    pub mesh: Option<Rc<TriangleMesh>>, // Triangle geometry for CollisionShape::TriangleMesh
+    pub convex_hull: Option<Rc<ConvexHull>>, // Vertex data for CollisionShape::ConvexHull
+    pub collision_groups: CollisionGroups, // Layer/mask collision filtering
+    pub one_way_normal: Option<Vector3>, // If set, only resists approach from this side (one-way platforms)
 
     // Force accumulators
     pub force_accum: Vector3,     // Accumulated force
     pub torque_accum: Vector3,    // Accumulated torque
+
+    // Drag
+    pub linear_damping: f32,  // Velocity-proportional drag coefficient on velocity
+    pub angular_damping: f32, // Velocity-proportional drag coefficient on angular_velocity
 }
 
 impl RigidBody {
@@ -152,8 +216,14 @@ impl RigidBody {
             shape: CollisionShape::Sphere,
             radius: 1.0,
             half_extents: Vector3::new(0.5, 0.5, 0.5),
+            mesh: None,
+            convex_hull: None,
+            collision_groups: CollisionGroups::all(),
+            one_way_normal: None,
             force_accum: Vector3::zero(),
             torque_accum: Vector3::zero(),
+            linear_damping: 0.0,
+            angular_damping: 0.0,
         }
     }
 
@@ -170,6 +240,81 @@ impl RigidBody {
         self.half_extents = half_extents;
     }
 
+    /// Fills the body-space `inertia_tensor`/`inv_inertia_tensor` from `shape`:
+    /// a solid sphere gets `I = diag(2/5 · mass · radius²)`; an AABB/OBB box
+    /// uses `half_extents (hx, hy, hz)`: `Ixx = mass/3·(hy²+hz²)`,
+    /// `Iyy = mass/3·(hx²+hz²)`, `Izz = mass/3·(hx²+hy²)`. Call this after
+    /// setting `mass` and the shape's dimensions; `integrate` rotates the
+    /// stored body-space inverse into world space each step.
+    pub fn recompute_inertia(&mut self) {
+        if self.mass <= 0.0 {
+            self.inertia_tensor = Matrix3::new();
+            self.inv_inertia_tensor = Matrix3::new();
+            return;
+        }
+
+        self.inertia_tensor = match self.shape {
+            CollisionShape::Sphere => {
+                let i = (2.0 / 5.0) * self.mass * self.radius * self.radius;
+                Matrix3::from_diagonal(i)
+            }
+            _ => {
+                let (hx, hy, hz) = (self.half_extents.x, self.half_extents.y, self.half_extents.z);
+                Matrix3::from_rows(
+                    Vector3::new(self.mass / 3.0 * (hy * hy + hz * hz), 0.0, 0.0),
+                    Vector3::new(0.0, self.mass / 3.0 * (hx * hx + hz * hz), 0.0),
+                    Vector3::new(0.0, 0.0, self.mass / 3.0 * (hx * hx + hy * hy)),
+                )
+            }
+        };
+        self.inv_inertia_tensor = self.inertia_tensor.inverse().unwrap_or_else(Matrix3::identity);
+    }
+
+    /// Sets the body-space inertia tensor from an arbitrary symmetric tensor (e.g.
+    /// one accumulated from a mesh/convex-hull's mass distribution), diagonalizing
+    /// it first via `Matrix3::diagonalize` so the stored tensor and its inverse stay
+    /// axis-aligned in the body's own frame. Only the principal moments are kept, on
+    /// the body's x/y/z axes; the principal axes are discarded, so the stored tensor
+    /// equals `tensor` only when `tensor` is already diagonal.
+    pub fn set_inertia_tensor(&mut self, tensor: Matrix3) {
+        let (_principal_axes, moments) = tensor.diagonalize();
+        self.inertia_tensor = Matrix3::from_diagonal(1.0);
+        self.inertia_tensor.m[0][0] = moments.x;
+        self.inertia_tensor.m[1][1] = moments.y;
+        self.inertia_tensor.m[2][2] = moments.z;
+        self.inv_inertia_tensor = self.inertia_tensor.inverse().unwrap_or_else(Matrix3::identity);
+    }
+
+    /// Attaches static triangle-mesh geometry to this body (shape should be set to
+    /// `CollisionShape::TriangleMesh`)
+    pub fn set_mesh(&mut self, mesh: Rc<TriangleMesh>) {
+        self.mesh = Some(mesh);
+    }
+
+    /// Attaches convex-hull geometry to this body (shape should be set to
+    /// `CollisionShape::ConvexHull`)
+    pub fn set_convex_hull(&mut self, hull: Rc<ConvexHull>) {
+        self.convex_hull = Some(hull);
+    }
+
+    /// Sets the collision filtering layer/mask for this body
+    pub fn set_collision_groups(&mut self, groups: CollisionGroups) {
+        self.collision_groups = groups;
+    }
+
+    /// Marks this body a one-way platform: contacts are only solved when the other
+    /// body is approaching from the side `normal` points toward, so bodies can pass
+    /// through from below but land on top
+    pub fn set_one_way_normal(&mut self, normal: Option<Vector3>) {
+        self.one_way_normal = normal;
+    }
+
+    /// Whether this body should generate contacts with `other`, per their
+    /// collision groups
+    pub fn should_collide(&self, other: &RigidBody) -> bool {
+        self.collision_groups.collides_with(&other.collision_groups)
+    }
+
     pub fn apply_force(&mut self, force: Vector3) {
         self.force_accum += force;
     }
@@ -185,22 +330,41 @@ impl RigidBody {
     }
 
     pub fn integrate(&mut self, dt: f32) {
+        self.integrate_with_damping_defaults(dt, 0.0, 0.0);
+    }
+
+    /// Same as `integrate`, but falls back to `default_linear_damping`/
+    /// `default_angular_damping` (e.g. `PhysicsWorld`'s global damping) for bodies that
+    /// leave their own `linear_damping`/`angular_damping` at the zero default
+    pub fn integrate_with_damping_defaults(&mut self, dt: f32, default_linear_damping: f32, default_angular_damping: f32) {
         if self.inv_mass <= 0.0 {
             return;
         }
 
-        // Update linear velocity and position
+        let linear_damping = if self.linear_damping > 0.0 { self.linear_damping } else { default_linear_damping };
+        let angular_damping = if self.angular_damping > 0.0 { self.angular_damping } else { default_angular_damping };
+
+        // Update linear velocity and position. Damping is applied implicitly (solving
+        // (1 + b*dt) v_new = v_old rather than subtracting b*v*dt) so it stays stable
+        // at large timesteps instead of overshooting into oscillation.
         self.acceleration = self.force_accum * self.inv_mass;
         self.velocity += self.acceleration * dt;
+        self.velocity *= 1.0 / (1.0 + linear_damping * dt);
         self.position += self.velocity * dt;
 
-        // Update angular velocity and rotation
-        let angular_acceleration = self.inv_inertia_tensor * self.torque_accum;
+        // Update angular velocity and rotation, rotating the body-space inverse
+        // inertia tensor into world space first so angular response reflects the
+        // body's current orientation rather than assuming it's axis-aligned
+        let rotation_matrix = self.rotation.to_matrix();
+        let world_inv_inertia_tensor = calculate_world_inertia_tensor(self.inv_inertia_tensor, rotation_matrix);
+        let angular_acceleration = world_inv_inertia_tensor * self.torque_accum;
         self.angular_velocity += angular_acceleration * dt;
+        self.angular_velocity *= 1.0 / (1.0 + angular_damping * dt);
 
-        // Update rotation using quaternion
-        let rotation_change = Quaternion::from_vector(self.angular_velocity * dt, 0.0);
-        self.rotation = self.rotation * rotation_change;
+        // Advance orientation by the spin quaternion dq/dt = 0.5 * (angular_velocity, 0) * q,
+        // so angular_velocity integrates as a true rotation rather than a linear offset
+        let spin = Quaternion::from_vector(self.angular_velocity, 0.0) * self.rotation * 0.5;
+        self.rotation = self.rotation + spin * dt;
         self.rotation.normalize();
 
         // Clear accumulated forces
@@ -213,6 +377,22 @@ impl RigidBody {
     }
 }
 
+/// Tunable constants for the positional correction applied in `resolve_collision`
+#[derive(Debug, Clone, Copy)]
+pub struct ResolutionParams {
+    pub correction_percent: f32, // Fraction of remaining penetration corrected per call
+    pub k_slop: f32,             // Penetration allowed to remain uncorrected, to stop resting-contact jitter
+}
+
+impl Default for ResolutionParams {
+    fn default() -> Self {
+        Self {
+            correction_percent: 0.2,
+            k_slop: 0.01,
+        }
+    }
+}
+
 /// Resolves a collision between two rigid bodies using impulse-based methods
 pub fn resolve_collision(
     body_a: &mut RigidBody,
@@ -220,10 +400,12 @@ pub fn resolve_collision(
     collision: &Collision,
     restitution: f32,
     friction_coeff: f32,
+    resolution_params: ResolutionParams,
 ) {
     let inv_mass_sum = body_a.inv_mass + body_b.inv_mass;
     if inv_mass_sum > 0.0 {
-        let correction = (collision.penetration / inv_mass_sum) * 0.5;
+        let correction = (collision.penetration - resolution_params.k_slop).max(0.0)
+            / inv_mass_sum * resolution_params.correction_percent;
         body_a.position -= collision.normal * (correction * body_a.inv_mass);
         body_b.position += collision.normal * (correction * body_b.inv_mass);
     }
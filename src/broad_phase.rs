@@ -1,9 +1,19 @@
 use crate::math_utils::Vector3;
-use crate::aabb::RigidBody;
-use std::collections::HashMap;
+use crate::aabb::{RigidBody, CollisionShape};
+use crate::collision::{compute_aabb, AABB};
+use std::collections::{HashMap, HashSet};
 use std::hash::{Hash, Hasher};
 use std::f32;
 
+/// A body's AABB half-extents for broad-phase insertion: its sphere radius on
+/// all three axes for `CollisionShape::Sphere`, or its `half_extents` otherwise
+fn body_half_extents(body: &RigidBody) -> Vector3 {
+    match body.shape {
+        CollisionShape::Sphere => Vector3::new(body.radius, body.radius, body.radius),
+        _ => body.half_extents,
+    }
+}
+
 /// Represents a 3D integer coordinate for a grid cell
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GridCoord {
@@ -72,22 +82,39 @@ impl UniformGridBroadPhase {
         neighbors
     }
 
-    /// Updates the grid by inserting all provided bodies into their corresponding cells
+    /// Updates the grid by inserting all provided bodies into their corresponding
+    /// cells. A body is registered in every cell its AABB overlaps, so a body
+    /// larger than one cell (or straddling a cell boundary) is still found by
+    /// `get_potential_pairs` no matter which of its cells the other body is in.
     pub fn update(&mut self, bodies: &[Box<RigidBody>]) {
         // Clear the grid for a new frame
         self.grid.clear();
         self.body_indices.clear();
 
-        // Insert each body into the appropriate cell
         for (i, body) in bodies.iter().enumerate() {
-            let cell_coord = self.get_cell_coord(&body.position);
-            self.grid.entry(cell_coord)
-                .or_insert_with(GridCell::default)
-                .bodies.push(i);
+            let half_extents = body_half_extents(body);
+            let min_coord = self.get_cell_coord(&(body.position - half_extents));
+            let max_coord = self.get_cell_coord(&(body.position + half_extents));
+
+            for x in min_coord.x..=max_coord.x {
+                for y in min_coord.y..=max_coord.y {
+                    for z in min_coord.z..=max_coord.z {
+                        self.grid.entry(GridCoord { x, y, z })
+                            .or_insert_with(GridCell::default)
+                            .bodies.push(i);
+                    }
+                }
+            }
             self.body_indices.push(i);
         }
     }
 
+    /// The grid coordinates of every non-empty cell, for visualizing broadphase
+    /// occupancy (e.g. in place of a cosmetic floor grid)
+    pub fn occupied_cells(&self) -> Vec<GridCoord> {
+        self.grid.keys().copied().collect()
+    }
+
     /// Helper function to check if two cells are close enough for potential collision
     fn are_neighbor_cells(a: &GridCoord, b: &GridCoord) -> bool {
         (a.x - b.x).abs() <= 1 &&
@@ -95,52 +122,731 @@ impl UniformGridBroadPhase {
         (a.z - b.z).abs() <= 1
     }
 
-    /// Returns a vector of potential colliding pairs based on grid occupancy
+    /// Returns the set of candidate colliding pairs: every pair of bodies that
+    /// shares at least one grid cell, deduplicated (a pair can share more than one
+    /// cell when one or both bodies span multiple cells). Each pair is ordered
+    /// `(min, max)` by body index.
     pub fn get_potential_pairs(&self) -> Vec<(usize, usize)> {
+        let mut seen = HashSet::new();
         let mut pairs = Vec::new();
-        pairs.reserve(100); // Reserve an arbitrary number to reduce reallocations
 
-        // Collect all occupied cells for easier iteration
-        let occupied_cells: Vec<_> = self.grid.iter().collect();
+        for cell in self.grid.values() {
+            for j in 0..cell.bodies.len() {
+                for k in (j + 1)..cell.bodies.len() {
+                    let a = cell.bodies[j];
+                    let b = cell.bodies[k];
+                    let pair = if a < b { (a, b) } else { (b, a) };
+                    if seen.insert(pair) {
+                        pairs.push(pair);
+                    }
+                }
+            }
+        }
+
+        pairs
+    }
+
+    /// Like `get_potential_pairs`, but additionally rejects any pair whose AABBs don't
+    /// actually overlap. Two bodies can share a grid cell (or several, if either spans
+    /// multiple cells) without their AABBs truly overlapping, so use this instead of
+    /// `get_potential_pairs` when box-level precision is worth the extra per-pair check.
+    pub fn get_potential_pairs_filtered(&self, bodies: &[Box<RigidBody>]) -> Vec<(usize, usize)> {
+        self.get_potential_pairs()
+            .into_iter()
+            .filter(|&(a, b)| {
+                let aabb_a = compute_aabb(bodies[a].position, body_half_extents(&bodies[a]));
+                let aabb_b = compute_aabb(bodies[b].position, body_half_extents(&bodies[b]));
+                aabb_a.min.x <= aabb_b.max.x && aabb_a.max.x >= aabb_b.min.x &&
+                aabb_a.min.y <= aabb_b.max.y && aabb_a.max.y >= aabb_b.min.y &&
+                aabb_a.min.z <= aabb_b.max.z && aabb_a.max.z >= aabb_b.min.z
+            })
+            .collect()
+    }
+
+    /// Gets the current cell size
+    pub fn cell_size(&self) -> f32 {
+        self.cell_size
+    }
+
+    /// Sets the cell size
+    pub fn set_cell_size(&mut self, size: f32) {
+        self.cell_size = size;
+    }
+
+    /// Clears the grid, ready for a fresh `insert` pass this frame
+    pub fn rebuild(&mut self) {
+        self.grid.clear();
+        self.body_indices.clear();
+    }
+
+    /// Inserts a single AABB into every grid cell it spans, registering it under
+    /// `index`. An alternative to `update` for callers that already have
+    /// world-space AABBs (convex hulls, ellipsoids, ...) rather than a `RigidBody`
+    /// list; call `rebuild()` first to start a new frame.
+    pub fn insert(&mut self, index: usize, aabb: &AABB) {
+        let min_coord = self.get_cell_coord(&aabb.min);
+        let max_coord = self.get_cell_coord(&aabb.max);
+
+        for x in min_coord.x..=max_coord.x {
+            for y in min_coord.y..=max_coord.y {
+                for z in min_coord.z..=max_coord.z {
+                    self.grid.entry(GridCoord { x, y, z })
+                        .or_insert_with(GridCell::default)
+                        .bodies.push(index);
+                }
+            }
+        }
+        self.body_indices.push(index);
+    }
+
+    /// Alias for `get_potential_pairs`, matching the `insert`/`rebuild` naming
+    pub fn candidate_pairs(&self) -> Vec<(usize, usize)> {
+        self.get_potential_pairs()
+    }
+
+    /// Returns the indices of bodies within `radius` of `bodies[index]`, for callers
+    /// like the steering subsystem that need a radius query around one body rather
+    /// than every colliding pair. Candidates are the bodies sharing at least one grid
+    /// cell with `bodies[index]` (so call `update` first this frame), then checked by
+    /// exact distance; a body within `radius` that shares no cell with it is not returned.
+    pub fn query_radius(&self, bodies: &[Box<RigidBody>], index: usize, radius: f32) -> Vec<usize> {
+        let origin = bodies[index].position;
+        let radius_sq = radius * radius;
+        self.get_potential_pairs()
+            .into_iter()
+            .filter_map(|(a, b)| {
+                if a == index {
+                    Some(b)
+                } else if b == index {
+                    Some(a)
+                } else {
+                    None
+                }
+            })
+            .filter(|&other| bodies[other].position.distance_squared(&origin) <= radius_sq)
+            .collect()
+    }
+}
+
+/// Which side of a proxy's AABB a `SapEndpoint` marks, along one axis
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SapEdgeKind {
+    Min,
+    Max,
+}
+
+/// One endpoint of a proxy's world AABB along a single axis, quantized to 16
+/// bits so the incremental sort compares cheap integers rather than floats
+#[derive(Debug, Clone, Copy)]
+struct SapEndpoint {
+    value: u16,
+    proxy: usize,
+    kind: SapEdgeKind,
+}
+
+/// Sweep-and-prune broad-phase using the incremental sort technique from
+/// Bullet's `btAxisSweep3`. Three per-axis endpoint arrays are kept (nearly)
+/// sorted across frames: each `update` quantizes the current AABBs onto the
+/// existing endpoint order, then an insertion sort settles them back into
+/// place, toggling a per-axis overlap bit every time a min endpoint swaps past
+/// a max endpoint (or vice versa). A pair is a broad-phase candidate once its
+/// three-axis bitmask is fully set. This is near-linear for coherent scenes
+/// (small per-frame motion), unlike `UniformGridBroadPhase`'s cell comparisons,
+/// and needs no cell-size tuning.
+pub struct SweepAndPruneBroadPhase {
+    axes: [Vec<SapEndpoint>; 3],
+    overlaps: HashMap<(usize, usize), u8>,
+}
+
+impl SweepAndPruneBroadPhase {
+    pub fn new() -> Self {
+        Self {
+            axes: [Vec::new(), Vec::new(), Vec::new()],
+            overlaps: HashMap::new(),
+        }
+    }
+
+    /// Quantizes a world-space coordinate to 16 bits against the scene's
+    /// current bounds on that axis
+    fn quantize(value: f32, min: f32, max: f32) -> u16 {
+        if max <= min {
+            return 0;
+        }
+        let t = ((value - min) / (max - min)).clamp(0.0, 1.0);
+        (t * u16::MAX as f32) as u16
+    }
+
+    /// Reads the given axis coordinate (0=x, 1=y, 2=z) off a `Vector3`
+    fn axis_value(v: Vector3, axis: usize) -> f32 {
+        match axis {
+            0 => v.x,
+            1 => v.y,
+            _ => v.z,
+        }
+    }
+
+    fn pair_key(a: usize, b: usize) -> (usize, usize) {
+        if a < b { (a, b) } else { (b, a) }
+    }
+
+    fn set_axis_bit(overlaps: &mut HashMap<(usize, usize), u8>, a: usize, b: usize, axis: usize) {
+        *overlaps.entry(Self::pair_key(a, b)).or_insert(0) |= 1 << axis;
+    }
+
+    fn clear_axis_bit(overlaps: &mut HashMap<(usize, usize), u8>, a: usize, b: usize, axis: usize) {
+        let key = Self::pair_key(a, b);
+        if let Some(mask) = overlaps.get_mut(&key) {
+            *mask &= !(1 << axis);
+            if *mask == 0 {
+                overlaps.remove(&key);
+            }
+        }
+    }
+
+    /// Updates the broad-phase for the current frame's bodies
+    pub fn update(&mut self, bodies: &[Box<RigidBody>]) {
+        if bodies.is_empty() {
+            for axis in self.axes.iter_mut() {
+                axis.clear();
+            }
+            self.overlaps.clear();
+            return;
+        }
+
+        let mut mins = vec![Vector3::zero(); bodies.len()];
+        let mut maxs = vec![Vector3::zero(); bodies.len()];
+        let mut scene_min = Vector3::new(f32::MAX, f32::MAX, f32::MAX);
+        let mut scene_max = Vector3::new(f32::MIN, f32::MIN, f32::MIN);
+        for (i, body) in bodies.iter().enumerate() {
+            let aabb = compute_aabb(body.position, body_half_extents(body));
+            mins[i] = aabb.min;
+            maxs[i] = aabb.max;
+            scene_min = Vector3::new(scene_min.x.min(aabb.min.x), scene_min.y.min(aabb.min.y), scene_min.z.min(aabb.min.z));
+            scene_max = Vector3::new(scene_max.x.max(aabb.max.x), scene_max.y.max(aabb.max.y), scene_max.z.max(aabb.max.z));
+        }
+
+        let rebuild = self.axes[0].len() != bodies.len() * 2;
+        if rebuild {
+            self.overlaps.clear();
+            for axis in 0..3 {
+                let mut endpoints = Vec::with_capacity(bodies.len() * 2);
+                for i in 0..bodies.len() {
+                    endpoints.push(SapEndpoint { value: 0, proxy: i, kind: SapEdgeKind::Min });
+                    endpoints.push(SapEndpoint { value: 0, proxy: i, kind: SapEdgeKind::Max });
+                }
+                self.axes[axis] = endpoints;
+            }
+        }
+
+        for axis in 0..3 {
+            let (lo, hi) = (Self::axis_value(scene_min, axis), Self::axis_value(scene_max, axis));
+            for endpoint in self.axes[axis].iter_mut() {
+                let raw = match endpoint.kind {
+                    SapEdgeKind::Min => Self::axis_value(mins[endpoint.proxy], axis),
+                    SapEdgeKind::Max => Self::axis_value(maxs[endpoint.proxy], axis),
+                };
+                endpoint.value = Self::quantize(raw, lo, hi);
+            }
+
+            if rebuild {
+                // Bootstrap this axis with a direct sweep over the freshly
+                // sorted array, since the insertion sort below only detects
+                // overlaps by observing a swap, and an array that happens to
+                // already be sorted would never swap.
+                self.axes[axis].sort_by_key(|e| e.value);
+                let mut active: Vec<usize> = Vec::new();
+                for endpoint in &self.axes[axis] {
+                    match endpoint.kind {
+                        SapEdgeKind::Min => {
+                            for &other in &active {
+                                Self::set_axis_bit(&mut self.overlaps, endpoint.proxy, other, axis);
+                            }
+                            active.push(endpoint.proxy);
+                        }
+                        SapEdgeKind::Max => {
+                            active.retain(|&p| p != endpoint.proxy);
+                        }
+                    }
+                }
+            } else {
+                // Insertion sort: the array is nearly sorted already (scene
+                // moved only slightly since last frame), so this settles in
+                // close to O(n). Every swap that crosses a min past a max (or
+                // a max past a min) means that pair's overlap on this axis
+                // just started or ended.
+                let endpoints = &mut self.axes[axis];
+                for i in 1..endpoints.len() {
+                    let mut j = i;
+                    while j > 0 && endpoints[j - 1].value > endpoints[j].value {
+                        let moving = endpoints[j];
+                        let other = endpoints[j - 1];
+                        match (moving.kind, other.kind) {
+                            (SapEdgeKind::Min, SapEdgeKind::Max) => {
+                                Self::set_axis_bit(&mut self.overlaps, moving.proxy, other.proxy, axis);
+                            }
+                            (SapEdgeKind::Max, SapEdgeKind::Min) => {
+                                Self::clear_axis_bit(&mut self.overlaps, moving.proxy, other.proxy, axis);
+                            }
+                            _ => {}
+                        }
+                        endpoints.swap(j - 1, j);
+                        j -= 1;
+                    }
+                }
+            }
+        }
+    }
+
+    /// Returns the set of candidate colliding pairs: every pair of bodies
+    /// whose AABBs overlap on all three axes, ordered `(min, max)` by body index
+    pub fn get_potential_pairs(&self) -> Vec<(usize, usize)> {
+        self.overlaps.iter()
+            .filter(|(_, &mask)| mask == 0b111)
+            .map(|(&pair, _)| pair)
+            .collect()
+    }
+}
+
+impl Default for SweepAndPruneBroadPhase {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A node in a `DynamicBvhBroadPhase` tree: a leaf holds one body's fat AABB
+/// and its proxy index, an internal node holds the union AABB of its children
+#[derive(Debug, Clone)]
+struct DbvtNode {
+    aabb: AABB,
+    parent: Option<usize>,
+    proxy: Option<usize>,
+    left: Option<usize>,
+    right: Option<usize>,
+}
+
+/// Dynamic bounding-volume tree broad-phase, following Bullet's `btDbvt`. Each
+/// body is a leaf with a "fat" AABB (its tight AABB expanded by `margin`);
+/// internal nodes store the union of their children. `update` only removes and
+/// reinserts a body when its tight AABB escapes its stored fat AABB, so a
+/// mostly-static or slow-moving scene stays untouched frame to frame — unlike
+/// `UniformGridBroadPhase`, which rebuilds from scratch every call. This
+/// also has no fixed cell size to tune, so it copes well with widely varying
+/// object sizes and sparse worlds.
+pub struct DynamicBvhBroadPhase {
+    nodes: Vec<DbvtNode>,
+    free_list: Vec<usize>,
+    root: Option<usize>,
+    leaf_of: HashMap<usize, usize>, // body (proxy) index -> its leaf node index
+    margin: f32,
+}
+
+impl DynamicBvhBroadPhase {
+    /// Creates a new tree that expands each body's tight AABB by `margin`
+    /// before storing it, so small motions don't trigger a reinsertion
+    pub fn new(margin: f32) -> Self {
+        Self {
+            nodes: Vec::new(),
+            free_list: Vec::new(),
+            root: None,
+            leaf_of: HashMap::new(),
+            margin,
+        }
+    }
+
+    fn union(a: &AABB, b: &AABB) -> AABB {
+        AABB {
+            min: Vector3::new(a.min.x.min(b.min.x), a.min.y.min(b.min.y), a.min.z.min(b.min.z)),
+            max: Vector3::new(a.max.x.max(b.max.x), a.max.y.max(b.max.y), a.max.z.max(b.max.z)),
+        }
+    }
+
+    fn surface_area(aabb: &AABB) -> f32 {
+        let d = aabb.max - aabb.min;
+        2.0 * (d.x * d.y + d.y * d.z + d.z * d.x)
+    }
+
+    fn overlaps(a: &AABB, b: &AABB) -> bool {
+        a.min.x <= b.max.x && a.max.x >= b.min.x &&
+        a.min.y <= b.max.y && a.max.y >= b.min.y &&
+        a.min.z <= b.max.z && a.max.z >= b.min.z
+    }
+
+    fn contains(outer: &AABB, inner: &AABB) -> bool {
+        outer.min.x <= inner.min.x && outer.min.y <= inner.min.y && outer.min.z <= inner.min.z &&
+        outer.max.x >= inner.max.x && outer.max.y >= inner.max.y && outer.max.z >= inner.max.z
+    }
+
+    fn fatten(aabb: AABB, margin: f32) -> AABB {
+        let m = Vector3::new(margin, margin, margin);
+        AABB { min: aabb.min - m, max: aabb.max + m }
+    }
+
+    fn allocate_node(&mut self, node: DbvtNode) -> usize {
+        if let Some(index) = self.free_list.pop() {
+            self.nodes[index] = node;
+            index
+        } else {
+            self.nodes.push(node);
+            self.nodes.len() - 1
+        }
+    }
+
+    /// Walks from `node`'s parent up to the root, refitting each ancestor's
+    /// AABB to the union of its two children
+    fn refit_ancestors(&mut self, node: usize) {
+        let mut current = self.nodes[node].parent;
+        while let Some(parent) = current {
+            let left = self.nodes[parent].left.unwrap();
+            let right = self.nodes[parent].right.unwrap();
+            self.nodes[parent].aabb = Self::union(&self.nodes[left].aabb, &self.nodes[right].aabb);
+            current = self.nodes[parent].parent;
+        }
+    }
+
+    /// Inserts a new leaf for `proxy`, descending from the root and at each
+    /// internal node picking whichever child's AABB union with `fat_aabb` has
+    /// the smaller surface area, then splicing in a new internal node above
+    /// the chosen sibling
+    fn insert_leaf(&mut self, proxy: usize, fat_aabb: AABB) {
+        let leaf = self.allocate_node(DbvtNode { aabb: fat_aabb, parent: None, proxy: Some(proxy), left: None, right: None });
+        self.leaf_of.insert(proxy, leaf);
+
+        let root = match self.root {
+            Some(root) => root,
+            None => {
+                self.root = Some(leaf);
+                return;
+            }
+        };
+
+        let mut index = root;
+        while self.nodes[index].proxy.is_none() {
+            let left = self.nodes[index].left.unwrap();
+            let right = self.nodes[index].right.unwrap();
+            let cost_left = Self::surface_area(&Self::union(&self.nodes[left].aabb, &fat_aabb));
+            let cost_right = Self::surface_area(&Self::union(&self.nodes[right].aabb, &fat_aabb));
+            index = if cost_left <= cost_right { left } else { right };
+        }
 
-        // Compare each cell with every other cell
-        for i in 0..occupied_cells.len() {
-            let (coord_a, cell_a) = occupied_cells[i];
+        let sibling = index;
+        let old_parent = self.nodes[sibling].parent;
+        let new_parent = self.allocate_node(DbvtNode {
+            aabb: Self::union(&self.nodes[sibling].aabb, &fat_aabb),
+            parent: old_parent,
+            proxy: None,
+            left: Some(sibling),
+            right: Some(leaf),
+        });
+        self.nodes[sibling].parent = Some(new_parent);
+        self.nodes[leaf].parent = Some(new_parent);
 
-            // 1) Add pairs among objects in the same cell
-            for j in 0..cell_a.bodies.len() {
-                for k in (j + 1)..cell_a.bodies.len() {
-                    pairs.push((cell_a.bodies[j], cell_a.bodies[k]));
+        match old_parent {
+            Some(op) => {
+                if self.nodes[op].left == Some(sibling) {
+                    self.nodes[op].left = Some(new_parent);
+                } else {
+                    self.nodes[op].right = Some(new_parent);
                 }
             }
+            None => self.root = Some(new_parent),
+        }
+
+        self.refit_ancestors(new_parent);
+    }
+
+    /// Removes `proxy`'s leaf, promoting its sibling to take its parent's place
+    fn remove_leaf(&mut self, proxy: usize) {
+        let leaf = match self.leaf_of.remove(&proxy) {
+            Some(leaf) => leaf,
+            None => return,
+        };
 
-            // 2) Check with other cells
-            for j in (i + 1)..occupied_cells.len() {
-                let (coord_b, cell_b) = occupied_cells[j];
+        match self.nodes[leaf].parent {
+            None => self.root = None,
+            Some(parent) => {
+                let sibling = if self.nodes[parent].left == Some(leaf) {
+                    self.nodes[parent].right.unwrap()
+                } else {
+                    self.nodes[parent].left.unwrap()
+                };
+                let grandparent = self.nodes[parent].parent;
 
-                // Only check cells that are immediate neighbors
-                if Self::are_neighbor_cells(coord_a, coord_b) {
-                    // Add pairs between objects in cell A and cell B
-                    for &body_a in &cell_a.bodies {
-                        for &body_b in &cell_b.bodies {
-                            pairs.push((body_a, body_b));
+                match grandparent {
+                    Some(gp) => {
+                        if self.nodes[gp].left == Some(parent) {
+                            self.nodes[gp].left = Some(sibling);
+                        } else {
+                            self.nodes[gp].right = Some(sibling);
                         }
+                        self.nodes[sibling].parent = Some(gp);
+                        self.refit_ancestors(sibling);
+                    }
+                    None => {
+                        self.root = Some(sibling);
+                        self.nodes[sibling].parent = None;
                     }
                 }
+                self.free_list.push(parent);
             }
         }
 
+        self.free_list.push(leaf);
+    }
+
+    /// Updates the tree for the current frame's bodies: a body not yet in the
+    /// tree is inserted, and a tracked body is only removed and reinserted
+    /// once its tight AABB has moved outside its stored fat AABB
+    pub fn update(&mut self, bodies: &[Box<RigidBody>]) {
+        for (i, body) in bodies.iter().enumerate() {
+            let tight = compute_aabb(body.position, body_half_extents(body));
+            match self.leaf_of.get(&i).copied() {
+                None => {
+                    self.insert_leaf(i, Self::fatten(tight, self.margin));
+                }
+                Some(leaf) => {
+                    if !Self::contains(&self.nodes[leaf].aabb, &tight) {
+                        self.remove_leaf(i);
+                        self.insert_leaf(i, Self::fatten(tight, self.margin));
+                    }
+                }
+            }
+        }
+    }
+
+    /// Recursively collects overlapping leaf pairs from a self-overlap
+    /// traversal of the subtrees rooted at `a` and `b`, pruning any branch
+    /// whose AABBs don't overlap
+    fn collect_pairs(&self, a: usize, b: usize, pairs: &mut Vec<(usize, usize)>) {
+        if a == b {
+            if let (Some(left), Some(right)) = (self.nodes[a].left, self.nodes[a].right) {
+                self.collect_pairs(left, left, pairs);
+                self.collect_pairs(right, right, pairs);
+                self.collect_pairs(left, right, pairs);
+            }
+            return;
+        }
+
+        if !Self::overlaps(&self.nodes[a].aabb, &self.nodes[b].aabb) {
+            return;
+        }
+
+        match (self.nodes[a].proxy, self.nodes[b].proxy) {
+            (Some(pa), Some(pb)) => {
+                pairs.push(if pa < pb { (pa, pb) } else { (pb, pa) });
+            }
+            (Some(_), None) => {
+                let (left, right) = (self.nodes[b].left.unwrap(), self.nodes[b].right.unwrap());
+                self.collect_pairs(a, left, pairs);
+                self.collect_pairs(a, right, pairs);
+            }
+            (None, Some(_)) => {
+                let (left, right) = (self.nodes[a].left.unwrap(), self.nodes[a].right.unwrap());
+                self.collect_pairs(left, b, pairs);
+                self.collect_pairs(right, b, pairs);
+            }
+            (None, None) => {
+                let (al, ar) = (self.nodes[a].left.unwrap(), self.nodes[a].right.unwrap());
+                let (bl, br) = (self.nodes[b].left.unwrap(), self.nodes[b].right.unwrap());
+                self.collect_pairs(al, bl, pairs);
+                self.collect_pairs(al, br, pairs);
+                self.collect_pairs(ar, bl, pairs);
+                self.collect_pairs(ar, br, pairs);
+            }
+        }
+    }
+
+    /// Returns the set of candidate colliding pairs found by a self-overlap
+    /// traversal of the tree, ordered `(min, max)` by body index
+    pub fn get_potential_pairs(&self) -> Vec<(usize, usize)> {
+        let mut pairs = Vec::new();
+        if let Some(root) = self.root {
+            self.collect_pairs(root, root, &mut pairs);
+        }
         pairs
     }
+}
 
-    /// Gets the current cell size
-    pub fn cell_size(&self) -> f32 {
-        self.cell_size
+struct OctreeNode {
+    bounds: AABB,
+    children: Option<[usize; 8]>,
+    bodies: Vec<(usize, AABB)>,
+    depth: u32,
+}
+
+/// An octree broad-phase: recursively subdivides the world's bounding
+/// `AABB` into 8 octants down to `max_depth` (or while a node holds fewer
+/// than `min_occupancy` bodies), inserting each body into the deepest node
+/// whose bounds fully contain its `compute_aabb`. Unlike `UniformGridBroadPhase`'s
+/// fixed `cell_size`, resolution adapts to how densely bodies are packed, and
+/// bodies too large to fit cleanly inside one child octant simply stay at the
+/// ancestor level that does contain them — so large objects naturally live
+/// high in the tree and small ones low.
+pub struct OctreeBroadPhase {
+    nodes: Vec<OctreeNode>,
+    max_depth: u32,
+    min_occupancy: usize,
+}
+
+impl OctreeBroadPhase {
+    /// Creates a new octree over `world_bounds`, subdividing nodes that hold
+    /// more than `min_occupancy` bodies until `max_depth` is reached
+    pub fn new(world_bounds: AABB, max_depth: u32, min_occupancy: usize) -> Self {
+        let root = OctreeNode { bounds: world_bounds, children: None, bodies: Vec::new(), depth: 0 };
+        Self { nodes: vec![root], max_depth, min_occupancy }
     }
 
-    /// Sets the cell size
-    pub fn set_cell_size(&mut self, size: f32) {
-        self.cell_size = size;
+    fn overlaps(a: &AABB, b: &AABB) -> bool {
+        a.min.x <= b.max.x && a.max.x >= b.min.x &&
+        a.min.y <= b.max.y && a.max.y >= b.min.y &&
+        a.min.z <= b.max.z && a.max.z >= b.min.z
+    }
+
+    fn contains(outer: &AABB, inner: &AABB) -> bool {
+        outer.min.x <= inner.min.x && outer.min.y <= inner.min.y && outer.min.z <= inner.min.z &&
+        outer.max.x >= inner.max.x && outer.max.y >= inner.max.y && outer.max.z >= inner.max.z
+    }
+
+    fn pair_key(a: usize, b: usize) -> (usize, usize) {
+        if a < b { (a, b) } else { (b, a) }
+    }
+
+    /// Splits `parent`'s bounds into its 8 octants, keyed by which half of
+    /// each axis (x, y, z) the octant occupies via bits 0, 1, 2
+    fn octant_bounds(parent: &AABB, octant: usize) -> AABB {
+        let center = (parent.min + parent.max) * 0.5;
+        let min = Vector3::new(
+            if octant & 1 == 0 { parent.min.x } else { center.x },
+            if octant & 2 == 0 { parent.min.y } else { center.y },
+            if octant & 4 == 0 { parent.min.z } else { center.z },
+        );
+        let max = Vector3::new(
+            if octant & 1 == 0 { center.x } else { parent.max.x },
+            if octant & 2 == 0 { center.y } else { parent.max.y },
+            if octant & 4 == 0 { center.z } else { parent.max.z },
+        );
+        AABB { min, max }
+    }
+
+    fn subdivide(&mut self, node_index: usize) {
+        let bounds = self.nodes[node_index].bounds;
+        let depth = self.nodes[node_index].depth;
+        let mut children = [0usize; 8];
+        for (octant, slot) in children.iter_mut().enumerate() {
+            *slot = self.nodes.len();
+            self.nodes.push(OctreeNode {
+                bounds: Self::octant_bounds(&bounds, octant),
+                children: None,
+                bodies: Vec::new(),
+                depth: depth + 1,
+            });
+        }
+        self.nodes[node_index].children = Some(children);
+    }
+
+    /// Inserts `proxy` into the deepest node whose bounds fully contain
+    /// `body_aabb`, subdividing nodes that exceed `min_occupancy` along the
+    /// way. A body that doesn't fit cleanly inside any single child octant
+    /// is kept at the current node instead of being forced down further.
+    fn insert(&mut self, node_index: usize, proxy: usize, body_aabb: AABB) {
+        if self.nodes[node_index].children.is_none() {
+            if self.nodes[node_index].depth < self.max_depth
+                && self.nodes[node_index].bodies.len() >= self.min_occupancy
+            {
+                self.subdivide(node_index);
+                // Push the node's existing bodies down into whichever children now contain them
+                let existing = std::mem::take(&mut self.nodes[node_index].bodies);
+                for (other, other_aabb) in existing {
+                    self.insert(node_index, other, other_aabb);
+                }
+            } else {
+                self.nodes[node_index].bodies.push((proxy, body_aabb));
+                return;
+            }
+        }
+
+        let children = self.nodes[node_index].children.unwrap();
+        for child in children {
+            if Self::contains(&self.nodes[child].bounds, &body_aabb) {
+                self.insert(child, proxy, body_aabb);
+                return;
+            }
+        }
+        self.nodes[node_index].bodies.push((proxy, body_aabb));
+    }
+
+    /// Rebuilds the tree from scratch, inserting every body's `compute_aabb`
+    /// into the deepest node whose bounds fully contain it
+    pub fn update(&mut self, bodies: &[Box<RigidBody>]) {
+        let root_bounds = self.nodes[0].bounds;
+        self.nodes.clear();
+        self.nodes.push(OctreeNode { bounds: root_bounds, children: None, bodies: Vec::new(), depth: 0 });
+
+        for (index, body) in bodies.iter().enumerate() {
+            let body_aabb = compute_aabb(body.position, body_half_extents(body));
+            self.insert(0, index, body_aabb);
+        }
+    }
+
+    /// Recurses through `node`, pairing its own bodies against each other and
+    /// against `ancestors` (bodies held at shallower nodes on the path to the
+    /// root), then passes its own bodies down as additional ancestors for its
+    /// children — so every pair is found exactly once, at the deeper of the
+    /// two nodes involved, covering own-node, ancestor, and descendant pairs.
+    fn collect_pairs(&self, node: usize, ancestors: &[(usize, AABB)], pairs: &mut Vec<(usize, usize)>) {
+        let bodies = &self.nodes[node].bodies;
+        for i in 0..bodies.len() {
+            for other in bodies.iter().skip(i + 1) {
+                if Self::overlaps(&bodies[i].1, &other.1) {
+                    pairs.push(Self::pair_key(bodies[i].0, other.0));
+                }
+            }
+            for ancestor in ancestors {
+                if Self::overlaps(&bodies[i].1, &ancestor.1) {
+                    pairs.push(Self::pair_key(bodies[i].0, ancestor.0));
+                }
+            }
+        }
+
+        if let Some(children) = self.nodes[node].children {
+            let mut combined = ancestors.to_vec();
+            combined.extend(bodies.iter().cloned());
+            for child in children {
+                self.collect_pairs(child, &combined, pairs);
+            }
+        }
+    }
+
+    /// Returns candidate colliding pairs found by recursing the tree once,
+    /// pruned by AABB overlap
+    pub fn get_potential_pairs(&self) -> Vec<(usize, usize)> {
+        let mut pairs = Vec::new();
+        self.collect_pairs(0, &[], &mut pairs);
+        pairs
+    }
+
+    fn query_region_recursive(&self, node: usize, region: &AABB, out: &mut Vec<usize>) {
+        if !Self::overlaps(&self.nodes[node].bounds, region) {
+            return;
+        }
+        for (proxy, aabb) in &self.nodes[node].bodies {
+            if Self::overlaps(aabb, region) {
+                out.push(*proxy);
+            }
+        }
+        if let Some(children) = self.nodes[node].children {
+            for child in children {
+                self.query_region_recursive(child, region, out);
+            }
+        }
+    }
+
+    /// Walks only the octants intersecting `region`, returning every body
+    /// whose AABB overlaps it. Useful for area-of-effect and trigger volumes,
+    /// a spatial query `UniformGridBroadPhase` doesn't expose.
+    pub fn query_region(&self, region: &AABB) -> Vec<usize> {
+        let mut out = Vec::new();
+        self.query_region_recursive(0, region, &mut out);
+        out
     }
 }
 
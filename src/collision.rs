@@ -1,5 +1,8 @@
 use crate::math_utils::{Vector3, Matrix3};
+use crate::math_utils::math_utils::calculate_world_inertia_tensor;
 use crate::aabb::RigidBody;
+use crate::convex::gjk_epa;
+use std::collections::HashMap;
 
 /// Represents an Axis-Aligned Bounding Box
 #[derive(Debug, Clone, Copy)]
@@ -16,6 +19,132 @@ pub struct OBB {
     pub rotation: Matrix3,
 }
 
+impl AABB {
+    /// Builds the axis-aligned box a `RigidBody` with `CollisionShape::AABB` presents to
+    /// the narrow phase: centered on its position, extending `half_extents` each way.
+    pub fn from_rigid_body(body: &RigidBody) -> Self {
+        compute_aabb(body.position, body.half_extents)
+    }
+}
+
+impl OBB {
+    /// Builds the oriented box a `RigidBody` with `CollisionShape::OBB` presents to the
+    /// narrow phase, rotating its `half_extents` by the body's current orientation.
+    pub fn from_rigid_body(body: &RigidBody) -> Self {
+        OBB {
+            position: body.position,
+            half_extents: body.half_extents,
+            rotation: body.rotation.to_matrix(),
+        }
+    }
+}
+
+/// A half-line used for picking, line-of-sight, and sensor probes: all points
+/// `origin + dir * t` for `t >= 0`. `dir` need not be normalized; the `t`
+/// returned by the `ray_vs_*` functions is in units of `dir`'s length.
+#[derive(Debug, Clone, Copy)]
+pub struct Ray {
+    pub origin: Vector3,
+    pub dir: Vector3,
+}
+
+/// One point in a `ContactManifold`: a world-space position, its penetration
+/// depth along the manifold's normal, and the accumulated normal impulse from
+/// the last time `collision::solve_manifolds` resolved it. Carry this value
+/// over into next frame's regenerated point (see `warm_start_manifold`) so the
+/// solver starts from the last known answer instead of zero.
+#[derive(Debug, Clone, Copy)]
+pub struct ContactPoint {
+    pub position: Vector3,
+    pub penetration: f32,
+    pub normal_impulse: f32,
+}
+
+/// A multi-point contact patch between two bodies, generated by clipping the
+/// incident face against the reference face (Sutherland-Hodgman) instead of
+/// collapsing a face-face contact to one averaged point. Letting the solver
+/// see all four corners is what lets stacked boxes resist toppling instead of
+/// jittering around a single pivot.
+#[derive(Debug, Clone)]
+pub struct ContactManifold {
+    pub normal: Vector3, // Points from body A to body B
+    pub points: Vec<ContactPoint>,
+}
+
+/// Persists each body pair's `ContactManifold` across simulation steps, keyed
+/// by `(min(a, b), max(a, b))` body indices, so next frame's regenerated
+/// manifold can be warm-started from this frame's accumulated impulses
+/// instead of starting from zero every step.
+#[derive(Debug, Clone, Default)]
+pub struct ContactCache {
+    manifolds: HashMap<(usize, usize), ContactManifold>,
+}
+
+impl ContactCache {
+    pub fn new() -> Self {
+        Self { manifolds: HashMap::new() }
+    }
+
+    fn key(a: usize, b: usize) -> (usize, usize) {
+        if a < b { (a, b) } else { (b, a) }
+    }
+
+    /// The manifold cached for this body pair last time `store` was called, if any
+    pub fn get(&self, a: usize, b: usize) -> Option<&ContactManifold> {
+        self.manifolds.get(&Self::key(a, b))
+    }
+
+    /// Replaces the cached manifold for this body pair
+    pub fn store(&mut self, a: usize, b: usize, manifold: ContactManifold) {
+        self.manifolds.insert(Self::key(a, b), manifold);
+    }
+
+    /// Clears the cached manifold for a body pair that's no longer in contact
+    pub fn remove(&mut self, a: usize, b: usize) {
+        self.manifolds.remove(&Self::key(a, b));
+    }
+}
+
+/// A single-point contact between two bodies awaiting velocity-iteration solving, carrying
+/// its own `accumulated_normal_impulse` across frames so the solver can warm-start instead
+/// of building up penetration response from zero every step. Used for every shape pair
+/// except OBB-OBB, which gets the richer multi-point `ContactManifold` instead.
+#[derive(Debug, Clone, Copy)]
+pub struct ContactConstraint {
+    pub body_a: usize,
+    pub body_b: usize,
+    pub normal: Vector3, // Points from body_a to body_b
+    pub penetration: f32,
+    pub accumulated_normal_impulse: f32,
+    /// Accumulated impulse along each axis of the tangent basis built from `normal`,
+    /// warm-started across frames the same way the normal impulse is
+    pub accumulated_tangent_impulse: [f32; 2],
+}
+
+/// Distinguishes a body pair's first frame of contact from a continuing contact or the
+/// frame contact broke, by comparing this step's resolved pairs against the ones resolved
+/// last step
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ContactPhase {
+    Enter,
+    Stay,
+    Exit,
+}
+
+/// One collision reported by `PhysicsWorld::drain_collision_events` after a step: the two
+/// body indices, the contact geometry, the normal impulse magnitude used to resolve it (0.0
+/// on `Exit`, since there's nothing left to resolve), and which `ContactPhase` it's in
+#[derive(Debug, Clone, Copy)]
+pub struct CollisionEvent {
+    pub body_a: usize,
+    pub body_b: usize,
+    pub point: Vector3,
+    pub normal: Vector3,
+    pub penetration: f32,
+    pub impulse: f32,
+    pub phase: ContactPhase,
+}
+
 /// Collision detection and resolution functions
 pub mod collision {
     use super::*;
@@ -36,151 +165,521 @@ pub mod collision {
         }
     }
 
-    /// Resolves a sphere-sphere collision
+    /// Builds a unit tangent direction orthogonal to `normal`, aligned with the
+    /// tangential component of `relative_velocity` whenever there is any sliding.
+    ///
+    /// If `normal × relative_velocity` is near zero (no sliding), an arbitrary
+    /// vector orthogonal to `normal` is returned instead so friction still has a
+    /// well-defined direction to act along.
+    fn contact_tangent(normal: Vector3, relative_velocity: Vector3) -> Vector3 {
+        let swing = normal.cross(&relative_velocity);
+        if swing.dot(&swing) < 1e-9 {
+            // No sliding: pick any vector orthogonal to the normal
+            let fallback = if normal.x.abs() < 0.9 {
+                Vector3::new(1.0, 0.0, 0.0)
+            } else {
+                Vector3::new(0.0, 1.0, 0.0)
+            };
+            (fallback - normal * fallback.dot(&normal)).normalize()
+        } else {
+            let s = (relative_velocity - normal * relative_velocity.dot(&normal)).normalize();
+            normal.cross(&s).cross(&normal).normalize()
+        }
+    }
+
+    /// Applies a Coulomb-clamped friction impulse opposing tangential sliding at a contact
+    fn apply_contact_friction(
+        a: &mut RigidBody,
+        b: &mut RigidBody,
+        normal: Vector3,
+        normal_impulse: f32,
+    ) {
+        let relative_vel = b.velocity - a.velocity;
+        let tangent = contact_tangent(normal, relative_vel);
+        let inv_mass_sum = a.inv_mass + b.inv_mass;
+        if inv_mass_sum <= 0.0 {
+            return;
+        }
+
+        let jt = -relative_vel.dot(&tangent) / inv_mass_sum;
+        let mu = (a.friction * b.friction).sqrt();
+        let max_friction = mu * normal_impulse.abs();
+        let jt = jt.clamp(-max_friction, max_friction);
+
+        let friction_impulse = tangent * jt;
+        a.velocity -= friction_impulse * a.inv_mass;
+        b.velocity += friction_impulse * b.inv_mass;
+    }
+
+    /// Resolves a sphere-sphere collision, returning the normal impulse magnitude applied
+    /// (0.0 if the bodies were already separating and nothing was resolved)
     pub fn resolve_sphere_sphere(
         a: &mut RigidBody,
         b: &mut RigidBody,
         normal: Vector3,
         penetration: f32,
         restitution: f32,
-        friction_coeff: f32,
-    ) {
+        _friction_coeff: f32,
+    ) -> f32 {
         // Calculate relative velocity
         let relative_vel = b.velocity - a.velocity;
         let normal_vel = relative_vel.dot(&normal);
 
         // Don't resolve if objects are moving apart
         if normal_vel > 0.0 {
-            return;
+            return 0.0;
         }
 
         // Calculate impulse
         let j = -(1.0 + restitution) * normal_vel;
         let j = j / (a.inv_mass + b.inv_mass);
-        let j = j / (1.0 + friction_coeff);
 
         // Apply impulse
         let impulse = normal * j;
         a.velocity = a.velocity - (impulse * a.inv_mass);
         b.velocity = b.velocity + (impulse * b.inv_mass);
 
+        // Tangential (Coulomb) friction at the contact
+        apply_contact_friction(a, b, normal, j);
+
         // Move objects apart
         let percent = 0.2; // Penetration slop
         let slop = 0.01;   // Penetration allowance
         let correction = normal * ((penetration - slop).max(0.0) * percent / (a.inv_mass + b.inv_mass));
         a.position = a.position - (correction * a.inv_mass);
         b.position = b.position + (correction * b.inv_mass);
+
+        j
     }
 
-    /// Resolves an AABB-AABB collision
+    /// Resolves an AABB-AABB collision, returning the normal impulse magnitude applied
+    /// (0.0 if the bodies were already separating and nothing was resolved)
     pub fn resolve_aabb_collision(
         a: &mut RigidBody,
         b: &mut RigidBody,
         normal: Vector3,
         penetration: f32,
         restitution: f32,
-        friction_coeff: f32,
-    ) {
+        _friction_coeff: f32,
+    ) -> f32 {
         // Calculate relative velocity
         let relative_vel = b.velocity - a.velocity;
         let normal_vel = relative_vel.dot(&normal);
 
         // Don't resolve if objects are moving apart
         if normal_vel > 0.0 {
-            return;
+            return 0.0;
         }
 
         // Calculate impulse
         let j = -(1.0 + restitution) * normal_vel;
         let j = j / (a.inv_mass + b.inv_mass);
-        let j = j / (1.0 + friction_coeff);
 
         // Apply impulse
         let impulse = normal * j;
         a.velocity = a.velocity - (impulse * a.inv_mass);
         b.velocity = b.velocity + (impulse * b.inv_mass);
 
+        // Tangential (Coulomb) friction at the contact
+        apply_contact_friction(a, b, normal, j);
+
         // Move objects apart
         let percent = 0.2; // Penetration slop
         let slop = 0.01;   // Penetration allowance
         let correction = normal * ((penetration - slop).max(0.0) * percent / (a.inv_mass + b.inv_mass));
         a.position = a.position - (correction * a.inv_mass);
         b.position = b.position + (correction * b.inv_mass);
+
+        j
     }
 
-    /// Resolves an OBB-OBB collision
+    /// Resolves an OBB-OBB collision, returning the normal impulse magnitude applied
+    /// (0.0 if the bodies were already separating and nothing was resolved)
     pub fn resolve_obb_collision(
         a: &mut RigidBody,
         b: &mut RigidBody,
         normal: Vector3,
         penetration: f32,
         restitution: f32,
-        friction_coeff: f32,
-    ) {
+        _friction_coeff: f32,
+    ) -> f32 {
         // Calculate relative velocity
         let relative_vel = b.velocity - a.velocity;
         let normal_vel = relative_vel.dot(&normal);
 
         // Don't resolve if objects are moving apart
         if normal_vel > 0.0 {
-            return;
+            return 0.0;
         }
 
         // Calculate impulse
         let j = -(1.0 + restitution) * normal_vel;
         let j = j / (a.inv_mass + b.inv_mass);
-        let j = j / (1.0 + friction_coeff);
 
         // Apply impulse
         let impulse = normal * j;
         a.velocity = a.velocity - (impulse * a.inv_mass);
         b.velocity = b.velocity + (impulse * b.inv_mass);
 
+        // Tangential (Coulomb) friction at the contact
+        apply_contact_friction(a, b, normal, j);
+
         // Move objects apart
         let percent = 0.2; // Penetration slop
         let slop = 0.01;   // Penetration allowance
         let correction = normal * ((penetration - slop).max(0.0) * percent / (a.inv_mass + b.inv_mass));
         a.position = a.position - (correction * a.inv_mass);
         b.position = b.position + (correction * b.inv_mass);
+
+        j
+    }
+
+    /// Resolves a convex hull-convex hull collision, returning the normal impulse magnitude
+    /// applied (0.0 if the bodies were already separating and nothing was resolved)
+    pub fn resolve_convex_collision(
+        a: &mut RigidBody,
+        b: &mut RigidBody,
+        normal: Vector3,
+        penetration: f32,
+        restitution: f32,
+        _friction_coeff: f32,
+    ) -> f32 {
+        // Calculate relative velocity
+        let relative_vel = b.velocity - a.velocity;
+        let normal_vel = relative_vel.dot(&normal);
+
+        // Don't resolve if objects are moving apart
+        if normal_vel > 0.0 {
+            return 0.0;
+        }
+
+        // Calculate impulse
+        let j = -(1.0 + restitution) * normal_vel;
+        let j = j / (a.inv_mass + b.inv_mass);
+
+        // Apply impulse
+        let impulse = normal * j;
+        a.velocity = a.velocity - (impulse * a.inv_mass);
+        b.velocity = b.velocity + (impulse * b.inv_mass);
+
+        // Tangential (Coulomb) friction at the contact
+        apply_contact_friction(a, b, normal, j);
+
+        // Move objects apart
+        let percent = 0.2; // Fraction of the excess penetration corrected
+        let slop = 0.01;   // Penetration allowance
+        let correction = normal * ((penetration - slop).max(0.0) * percent / (a.inv_mass + b.inv_mass));
+        a.position = a.position - (correction * a.inv_mass);
+        b.position = b.position + (correction * b.inv_mass);
+
+        j
     }
 
-    /// Resolves an OBB-AABB collision
+    /// Resolves an OBB-AABB collision, returning the normal impulse magnitude applied
+    /// (0.0 if the bodies were already separating and nothing was resolved)
     pub fn resolve_obb_aabb_collision(
         a: &mut RigidBody,
         b: &mut RigidBody,
         normal: Vector3,
         penetration: f32,
         restitution: f32,
-        friction_coeff: f32,
-    ) {
+        _friction_coeff: f32,
+    ) -> f32 {
         // Calculate relative velocity
         let relative_vel = b.velocity - a.velocity;
         let normal_vel = relative_vel.dot(&normal);
 
         // Don't resolve if objects are moving apart
         if normal_vel > 0.0 {
-            return;
+            return 0.0;
         }
 
         // Calculate impulse
         let j = -(1.0 + restitution) * normal_vel;
         let j = j / (a.inv_mass + b.inv_mass);
-        let j = j / (1.0 + friction_coeff);
 
         // Apply impulse
         let impulse = normal * j;
         a.velocity = a.velocity - (impulse * a.inv_mass);
         b.velocity = b.velocity + (impulse * b.inv_mass);
 
+        // Tangential (Coulomb) friction at the contact
+        apply_contact_friction(a, b, normal, j);
+
         // Move objects apart
         let percent = 0.2; // Penetration slop
         let slop = 0.01;   // Penetration allowance
         let correction = normal * ((penetration - slop).max(0.0) * percent / (a.inv_mass + b.inv_mass));
         a.position = a.position - (correction * a.inv_mass);
         b.position = b.position + (correction * b.inv_mass);
+
+        j
+    }
+
+    /// Resolves a multi-point `ContactManifold` between `bodies[a_idx]` and
+    /// `bodies[b_idx]` with a sequential-impulse solver: `iterations` velocity
+    /// passes over every point (each point's impulse is accumulated and
+    /// clamped to stay non-negative, so a later pass can correct an earlier
+    /// overshoot), followed by one Baumgarte positional-correction pass once
+    /// velocities have settled. Warm-start a point by setting its
+    /// `normal_impulse` before calling this (see `warm_start_manifold`).
+    pub fn solve_manifolds(
+        bodies: &mut [Box<RigidBody>],
+        manifolds: &mut [(usize, usize, ContactManifold)],
+        restitution: f32,
+        _friction_coeff: f32,
+        iterations: usize,
+    ) {
+        for (a_idx, b_idx, manifold) in manifolds.iter_mut() {
+            let (a_idx, b_idx) = (*a_idx, *b_idx);
+            if a_idx == b_idx {
+                continue;
+            }
+
+            for _ in 0..iterations {
+                for point in manifold.points.iter_mut() {
+                    let (a, b) = if a_idx < b_idx {
+                        let (left, right) = bodies.split_at_mut(b_idx);
+                        (&mut left[a_idx], &mut right[0])
+                    } else {
+                        let (left, right) = bodies.split_at_mut(a_idx);
+                        (&mut right[0], &mut left[b_idx])
+                    };
+
+                    let inv_mass_sum = a.inv_mass + b.inv_mass;
+                    if inv_mass_sum <= 0.0 {
+                        continue;
+                    }
+
+                    let relative_vel = b.velocity - a.velocity;
+                    let normal_vel = relative_vel.dot(&manifold.normal);
+
+                    let target_impulse = -(1.0 + restitution) * normal_vel / inv_mass_sum;
+                    let old_impulse = point.normal_impulse;
+                    let new_impulse = (old_impulse + target_impulse).max(0.0);
+                    let delta = new_impulse - old_impulse;
+                    point.normal_impulse = new_impulse;
+
+                    let impulse = manifold.normal * delta;
+                    a.velocity = a.velocity - (impulse * a.inv_mass);
+                    b.velocity = b.velocity + (impulse * b.inv_mass);
+
+                    apply_contact_friction(a, b, manifold.normal, new_impulse);
+                }
+            }
+
+            let percent = 0.2; // Fraction of the excess penetration corrected
+            let slop = 0.01;   // Penetration allowance
+            for point in manifold.points.iter() {
+                let (a, b) = if a_idx < b_idx {
+                    let (left, right) = bodies.split_at_mut(b_idx);
+                    (&mut left[a_idx], &mut right[0])
+                } else {
+                    let (left, right) = bodies.split_at_mut(a_idx);
+                    (&mut right[0], &mut left[b_idx])
+                };
+
+                let inv_mass_sum = a.inv_mass + b.inv_mass;
+                if inv_mass_sum <= 0.0 {
+                    continue;
+                }
+
+                let correction = manifold.normal * ((point.penetration - slop).max(0.0) * percent / inv_mass_sum);
+                a.position = a.position - (correction * a.inv_mass);
+                b.position = b.position + (correction * b.inv_mass);
+            }
+        }
+    }
+
+    /// Same sequential-impulse solve as `solve_manifolds`, but for a single
+    /// already-borrowed body pair rather than indexing into a shared body
+    /// slice. Use this when the caller already holds `&mut RigidBody` for both
+    /// sides (e.g. from a broad-phase pair loop that's split the body list).
+    ///
+    /// Unlike `solve_manifolds`, this applies each point's impulse at its own
+    /// world-space `position` rather than at the bodies' centers of mass, so a
+    /// box struck off-center picks up spin instead of only translating: the
+    /// effective mass seen by each point includes the angular term
+    /// `n·((I⁻¹·(r×n))×r)` from its lever arm `r = point.position - body.position`,
+    /// and every applied impulse updates `angular_velocity` through the
+    /// world-space inverse inertia tensor alongside `velocity`.
+    /// Returns the sum of the manifold points' final normal impulses, as a
+    /// scalar summary of how hard the two bodies hit each other this step.
+    pub fn solve_manifold_pair(
+        a: &mut RigidBody,
+        b: &mut RigidBody,
+        manifold: &mut ContactManifold,
+        restitution: f32,
+        iterations: usize,
+    ) -> f32 {
+        let inv_mass_sum = a.inv_mass + b.inv_mass;
+        if inv_mass_sum <= 0.0 {
+            return 0.0;
+        }
+
+        let world_inv_inertia_a = calculate_world_inertia_tensor(a.inv_inertia_tensor, a.rotation.to_matrix());
+        let world_inv_inertia_b = calculate_world_inertia_tensor(b.inv_inertia_tensor, b.rotation.to_matrix());
+        let (tangent1, tangent2) = orthonormal_tangent_basis(manifold.normal);
+        let mu = (a.friction * b.friction).sqrt();
+
+        for _ in 0..iterations {
+            for point in manifold.points.iter_mut() {
+                let ra = point.position - a.position;
+                let rb = point.position - b.position;
+
+                let relative_vel = (b.velocity + b.angular_velocity.cross(&rb)) - (a.velocity + a.angular_velocity.cross(&ra));
+                let normal_vel = relative_vel.dot(&manifold.normal);
+
+                let k = contact_effective_mass(manifold.normal, ra, rb, world_inv_inertia_a, world_inv_inertia_b, inv_mass_sum);
+                let target_impulse = -(1.0 + restitution) * normal_vel / k;
+                let old_impulse = point.normal_impulse;
+                let new_impulse = (old_impulse + target_impulse).max(0.0);
+                let delta = new_impulse - old_impulse;
+                point.normal_impulse = new_impulse;
+
+                let impulse = manifold.normal * delta;
+                a.velocity = a.velocity - (impulse * a.inv_mass);
+                b.velocity = b.velocity + (impulse * b.inv_mass);
+                a.angular_velocity = a.angular_velocity - (world_inv_inertia_a * ra.cross(&impulse));
+                b.angular_velocity = b.angular_velocity + (world_inv_inertia_b * rb.cross(&impulse));
+
+                // Two-direction Coulomb friction, solved with the same lever-arm-aware
+                // effective mass as the normal impulse above
+                let max_friction = mu * new_impulse;
+                for axis in [tangent1, tangent2] {
+                    let relative_vel = (b.velocity + b.angular_velocity.cross(&rb)) - (a.velocity + a.angular_velocity.cross(&ra));
+                    let tangent_vel = relative_vel.dot(&axis);
+                    let k_t = contact_effective_mass(axis, ra, rb, world_inv_inertia_a, world_inv_inertia_b, inv_mass_sum);
+                    let jt = (-tangent_vel / k_t).clamp(-max_friction, max_friction);
+
+                    let friction_impulse = axis * jt;
+                    a.velocity = a.velocity - (friction_impulse * a.inv_mass);
+                    b.velocity = b.velocity + (friction_impulse * b.inv_mass);
+                    a.angular_velocity = a.angular_velocity - (world_inv_inertia_a * ra.cross(&friction_impulse));
+                    b.angular_velocity = b.angular_velocity + (world_inv_inertia_b * rb.cross(&friction_impulse));
+                }
+            }
+        }
+
+        let percent = 0.2; // Fraction of the excess penetration corrected
+        let slop = 0.01;   // Penetration allowance
+        for point in manifold.points.iter() {
+            let correction = manifold.normal * ((point.penetration - slop).max(0.0) * percent / inv_mass_sum);
+            a.position = a.position - (correction * a.inv_mass);
+            b.position = b.position + (correction * b.inv_mass);
+        }
+
+        manifold.points.iter().map(|point| point.normal_impulse).sum()
+    }
+
+    /// Runs `iterations` passes of warm-started sequential-impulse solving over every
+    /// `ContactConstraint` in `constraints`, applying a Baumgarte bias velocity (scaled by
+    /// `beta`, see `PhysicsWorld::set_contact_bias_factor`) so penetration is corrected as
+    /// part of the velocity solve rather than as a separate positional shove. Each
+    /// constraint's `accumulated_normal_impulse` is clamped to stay non-negative (a contact
+    /// can only push, never pull) and carries over between calls, so callers that persist
+    /// `constraints` across frames get warm starting for free.
+    pub fn solve_contact_constraints(
+        bodies: &mut [Box<RigidBody>],
+        constraints: &mut [ContactConstraint],
+        restitution: f32,
+        iterations: u32,
+        dt: f32,
+        beta: f32,
+    ) {
+        const SLOP: f32 = 0.01;
+
+        for constraint in constraints.iter_mut() {
+            let (a_idx, b_idx) = (constraint.body_a, constraint.body_b);
+            let (a, b) = if a_idx < b_idx {
+                let (left, right) = bodies.split_at_mut(b_idx);
+                (&mut left[a_idx], &mut right[0])
+            } else {
+                let (left, right) = bodies.split_at_mut(a_idx);
+                (&mut right[0], &mut left[b_idx])
+            };
+
+            let inv_mass_sum = a.inv_mass + b.inv_mass;
+            if inv_mass_sum <= 0.0 {
+                continue;
+            }
+            let eff_mass = 1.0 / inv_mass_sum;
+            let bias = (beta / dt) * (constraint.penetration - SLOP).max(0.0);
+            let (tangent1, tangent2) = orthonormal_tangent_basis(constraint.normal);
+            let mu = (a.friction * b.friction).sqrt();
+
+            for _ in 0..iterations {
+                let relative_vel = b.velocity - a.velocity;
+                let normal_vel = relative_vel.dot(&constraint.normal);
+
+                let delta = -eff_mass * (1.0 + restitution) * normal_vel + eff_mass * bias;
+                let old_impulse = constraint.accumulated_normal_impulse;
+                let new_impulse = (old_impulse + delta).max(0.0);
+                let delta = new_impulse - old_impulse;
+                constraint.accumulated_normal_impulse = new_impulse;
+
+                // Static bodies (inv_mass == 0) are left untouched rather than rewritten
+                let impulse = constraint.normal * delta;
+                if a.inv_mass > 0.0 { a.velocity = a.velocity - (impulse * a.inv_mass); }
+                if b.inv_mass > 0.0 { b.velocity = b.velocity + (impulse * b.inv_mass); }
+
+                // Two-direction Coulomb friction: solve each tangent axis against the
+                // friction cone radius implied by this iteration's normal impulse, rather
+                // than a single ad-hoc sliding-direction damping term
+                let max_friction = mu * new_impulse;
+                for axis_index in 0..2 {
+                    let axis = if axis_index == 0 { tangent1 } else { tangent2 };
+                    let relative_vel = b.velocity - a.velocity;
+                    let tangent_vel = relative_vel.dot(&axis);
+                    let target = -eff_mass * tangent_vel;
+                    let old_tangent = constraint.accumulated_tangent_impulse[axis_index];
+                    let new_tangent = (old_tangent + target).clamp(-max_friction, max_friction);
+                    let tangent_delta = new_tangent - old_tangent;
+                    constraint.accumulated_tangent_impulse[axis_index] = new_tangent;
+
+                    let friction_impulse = axis * tangent_delta;
+                    if a.inv_mass > 0.0 { a.velocity = a.velocity - (friction_impulse * a.inv_mass); }
+                    if b.inv_mass > 0.0 { b.velocity = b.velocity + (friction_impulse * b.inv_mass); }
+                }
+            }
+        }
     }
 }
 
+/// Builds an orthonormal tangent basis `(t1, t2)` perpendicular to `normal`, by crossing
+/// `normal` with whichever world axis is least aligned with it (to avoid a
+/// near-degenerate cross product) and then crossing again for the second axis
+fn orthonormal_tangent_basis(normal: Vector3) -> (Vector3, Vector3) {
+    let axis = if normal.x.abs() <= normal.y.abs() && normal.x.abs() <= normal.z.abs() {
+        Vector3::new(1.0, 0.0, 0.0)
+    } else if normal.y.abs() <= normal.z.abs() {
+        Vector3::new(0.0, 1.0, 0.0)
+    } else {
+        Vector3::new(0.0, 0.0, 1.0)
+    };
+    let tangent1 = normal.cross(&axis).normalize();
+    let tangent2 = normal.cross(&tangent1);
+    (tangent1, tangent2)
+}
+
+/// The effective mass seen along `axis` by an impulse applied at a contact point with
+/// lever arms `ra`/`rb` from each body's center of mass: the linear `inv_mass_sum` plus
+/// each body's angular contribution `axis·((I⁻¹·(r×axis))×r)`. Dividing the desired
+/// velocity change by this (rather than by `inv_mass_sum` alone) is what lets an
+/// off-center impulse spend some of itself spinning the body instead of only pushing it.
+fn contact_effective_mass(
+    axis: Vector3,
+    ra: Vector3,
+    rb: Vector3,
+    world_inv_inertia_a: Matrix3,
+    world_inv_inertia_b: Matrix3,
+    inv_mass_sum: f32,
+) -> f32 {
+    let angular_a = (world_inv_inertia_a * ra.cross(&axis)).cross(&ra);
+    let angular_b = (world_inv_inertia_b * rb.cross(&axis)).cross(&rb);
+    inv_mass_sum + axis.dot(&angular_a) + axis.dot(&angular_b)
+}
+
 /// Global collision functions
 pub fn compute_aabb(position: Vector3, half_extents: Vector3) -> AABB {
     AABB {
@@ -218,7 +717,18 @@ pub fn compute_aabb_collision(a: &AABB, b: &AABB) -> Option<(f32, Vector3)> {
     Some((penetration, normal))
 }
 
-pub fn compute_obb_collision(a: &OBB, b: &OBB) -> Option<(f32, Vector3)> {
+/// Which SAT axis tested by `compute_obb_collision` produced the minimum-penetration normal:
+/// one of A's face normals, one of B's face normals, or the cross product of an edge
+/// from each. `generate_obb_manifold` uses this to pick the reference/incident faces
+/// without having to re-derive which axis won by re-testing face alignment.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SatAxis {
+    FaceA(usize),
+    FaceB(usize),
+    EdgeEdge(usize, usize),
+}
+
+pub fn compute_obb_collision(a: &OBB, b: &OBB) -> Option<(f32, Vector3, SatAxis)> {
     // Transform B's axes to A's local space
     let r = a.rotation * b.rotation.transpose();
     let mut t = b.position - a.position;
@@ -227,6 +737,7 @@ pub fn compute_obb_collision(a: &OBB, b: &OBB) -> Option<(f32, Vector3)> {
     // Test all 15 separating axes
     let mut min_overlap = f32::MAX;
     let mut min_normal = Vector3::zero();
+    let mut min_axis = SatAxis::FaceA(0);
 
     // Test A's axes
     for i in 0..3 {
@@ -253,6 +764,7 @@ pub fn compute_obb_collision(a: &OBB, b: &OBB) -> Option<(f32, Vector3)> {
                 2 => Vector3::new(0.0, 0.0, 1.0),
                 _ => unreachable!(),
             };
+            min_axis = SatAxis::FaceA(i);
         }
     }
 
@@ -271,6 +783,7 @@ pub fn compute_obb_collision(a: &OBB, b: &OBB) -> Option<(f32, Vector3)> {
         if overlap < min_overlap {
             min_overlap = overlap;
             min_normal = Vector3::new(r.m[0][i], r.m[1][i], r.m[2][i]);
+            min_axis = SatAxis::FaceB(i);
         }
     }
 
@@ -311,11 +824,87 @@ pub fn compute_obb_collision(a: &OBB, b: &OBB) -> Option<(f32, Vector3)> {
             if overlap < min_overlap {
                 min_overlap = overlap;
                 min_normal = axis;
+                min_axis = SatAxis::EdgeEdge(i, j);
             }
         }
     }
 
-    Some((min_overlap, a.rotation * min_normal))
+    // Guarantee the normal points from A toward B regardless of which axis or
+    // cross-product sign produced it, so callers never push a body the wrong way
+    let mut world_normal = a.rotation * min_normal;
+    if (b.position - a.position).dot(&world_normal) < 0.0 {
+        world_normal = world_normal * -1.0;
+    }
+
+    Some((min_overlap, world_normal, min_axis))
+}
+
+/// Half the extent `obb` projects onto world-space `axis` (assumed normalized):
+/// the sum of each local half-extent scaled by how aligned that local axis is with it.
+fn projected_radius(obb: &OBB, axis: Vector3) -> f32 {
+    obb.half_extents.x * obb_axis(&obb.rotation, 0).dot(&axis).abs()
+        + obb.half_extents.y * obb_axis(&obb.rotation, 1).dot(&axis).abs()
+        + obb.half_extents.z * obb_axis(&obb.rotation, 2).dot(&axis).abs()
+}
+
+/// Swept (continuous) OBB-vs-OBB time of impact over `[0, dt]`, for bodies moving at
+/// constant linear velocity `vel_a`/`vel_b`. Runs all 15 SAT axes (each box's 3 local
+/// axes plus all 9 of their cross products), and instead of testing overlap at a
+/// single instant as `compute_obb_collision` does, projects both boxes' 1-D intervals
+/// and the relative velocity along each axis to find the interval `[t_enter, t_exit]`
+/// during which that axis reports overlap. The boxes actually collide within `[0, dt]`
+/// only if every axis's overlap interval intersects, i.e. `max(t_enter) <= min(t_exit)`;
+/// that maximum, clamped to `0.0`, is the returned time of impact. Returns `None` if any
+/// axis stays separated for the whole interval (a valid separating axis for all of `[0, dt]`).
+pub fn compute_obb_toi(a: &OBB, vel_a: &Vector3, b: &OBB, vel_b: &Vector3, dt: f32) -> Option<f32> {
+    let mut global_entry = f32::NEG_INFINITY;
+    let mut global_exit = f32::INFINITY;
+
+    let mut test_axis = |axis: Vector3| -> Option<()> {
+        let length = axis.length();
+        if length < 1e-6 {
+            return Some(()); // Degenerate cross product (parallel edges); skip this axis
+        }
+        let axis = axis * (1.0 / length);
+
+        let diff0 = (b.position - a.position).dot(&axis);
+        let sum = projected_radius(a, axis) + projected_radius(b, axis);
+        let s = (*vel_b - *vel_a).dot(&axis);
+
+        if s.abs() < 1e-8 {
+            if diff0.abs() > sum {
+                return None; // Already separated on this axis and relative velocity never closes the gap
+            }
+            return Some(()); // Already overlapping on this axis for the whole interval; no constraint
+        }
+
+        let t1 = (sum - diff0) / s;
+        let t2 = (-sum - diff0) / s;
+        let (entry, exit) = (t1.min(t2), t1.max(t2));
+        global_entry = global_entry.max(entry);
+        global_exit = global_exit.min(exit);
+        if global_entry > global_exit {
+            return None;
+        }
+        Some(())
+    };
+
+    for i in 0..3 {
+        test_axis(obb_axis(&a.rotation, i))?;
+    }
+    for i in 0..3 {
+        test_axis(obb_axis(&b.rotation, i))?;
+    }
+    for i in 0..3 {
+        for j in 0..3 {
+            test_axis(obb_axis(&a.rotation, i).cross(&obb_axis(&b.rotation, j)))?;
+        }
+    }
+
+    if global_exit < 0.0 || global_entry > dt {
+        return None;
+    }
+    Some(global_entry.max(0.0))
 }
 
 pub fn compute_obb_aabb_collision(obb: &OBB, aabb: &AABB) -> Option<(f32, Vector3)> {
@@ -426,4 +1015,372 @@ pub fn compute_obb_aabb_collision(obb: &OBB, aabb: &AABB) -> Option<(f32, Vector
     }
 
     Some((min_overlap, obb.rotation * min_normal))
-} 
\ No newline at end of file
+}
+
+/// Tests two convex-hull bodies for collision using GJK/EPA, returning the
+/// penetration depth and world-space normal pointing from `a` to `b`
+pub fn compute_convex_collision(a: &RigidBody, b: &RigidBody) -> Option<(f32, Vector3)> {
+    let hull_a = a.convex_hull.as_ref()?;
+    let hull_b = b.convex_hull.as_ref()?;
+    let contact = gjk_epa(hull_a, a.position, hull_b, b.position)?;
+    Some((contact.penetration, contact.normal))
+}
+
+/// Casts a ray against an AABB using the slab method, returning the hit
+/// distance `t` (in units of `ray.dir`'s length) and the outward surface
+/// normal at the entry point. Returns `None` if the ray misses or the box is
+/// entirely behind the origin.
+pub fn ray_vs_aabb(ray: &Ray, aabb: &AABB) -> Option<(f32, Vector3)> {
+    let mut tmin = f32::MIN;
+    let mut tmax = f32::MAX;
+    let mut normal = Vector3::zero();
+
+    for axis in 0..3 {
+        let (origin, dir, min, max) = match axis {
+            0 => (ray.origin.x, ray.dir.x, aabb.min.x, aabb.max.x),
+            1 => (ray.origin.y, ray.dir.y, aabb.min.y, aabb.max.y),
+            _ => (ray.origin.z, ray.dir.z, aabb.min.z, aabb.max.z),
+        };
+
+        let t1 = (min - origin) / dir;
+        let t2 = (max - origin) / dir;
+        // t1 < t2 means the ray travels in +axis, so the min face is entered first
+        let (near, far, sign) = if t1 < t2 { (t1, t2, -1.0) } else { (t2, t1, 1.0) };
+
+        if near > tmin {
+            tmin = near;
+            normal = match axis {
+                0 => Vector3::new(sign, 0.0, 0.0),
+                1 => Vector3::new(0.0, sign, 0.0),
+                _ => Vector3::new(0.0, 0.0, sign),
+            };
+        }
+        tmax = tmax.min(far);
+    }
+
+    if tmin > tmax || tmax < 0.0 {
+        return None;
+    }
+
+    Some((tmin, normal))
+}
+
+/// Casts a ray against an OBB by transforming it into the box's local frame
+/// with `rotation.transpose()` (the same trick `compute_obb_aabb_collision`
+/// uses), reusing `ray_vs_aabb` there, and rotating the resulting normal back
+/// into world space with `rotation`.
+pub fn ray_vs_obb(ray: &Ray, obb: &OBB) -> Option<(f32, Vector3)> {
+    let local_ray = Ray {
+        origin: obb.rotation.transpose() * (ray.origin - obb.position),
+        dir: obb.rotation.transpose() * ray.dir,
+    };
+    let local_aabb = AABB {
+        min: obb.half_extents * -1.0,
+        max: obb.half_extents,
+    };
+
+    let (t, local_normal) = ray_vs_aabb(&local_ray, &local_aabb)?;
+    Some((t, obb.rotation * local_normal))
+}
+
+/// Casts a ray against a sphere, returning the nearest non-negative hit
+/// distance `t` and the outward surface normal at the hit point
+pub fn ray_vs_sphere(ray: &Ray, center: Vector3, radius: f32) -> Option<(f32, Vector3)> {
+    let offset = ray.origin - center;
+    let a = ray.dir.dot(&ray.dir);
+    let b = 2.0 * offset.dot(&ray.dir);
+    let c = offset.dot(&offset) - radius * radius;
+
+    let discriminant = b * b - 4.0 * a * c;
+    if discriminant < 0.0 {
+        return None;
+    }
+    let sqrt_discriminant = discriminant.sqrt();
+    let t_near = (-b - sqrt_discriminant) / (2.0 * a);
+    let t_far = (-b + sqrt_discriminant) / (2.0 * a);
+
+    let t = if t_near >= 0.0 {
+        t_near
+    } else if t_far >= 0.0 {
+        t_far
+    } else {
+        return None;
+    };
+
+    let hit_point = ray.origin + ray.dir * t;
+    Some((t, (hit_point - center).normalize()))
+}
+
+/// Reads axis `i` (0=x, 1=y, 2=z) of a `Vector3`
+fn vector_component(v: Vector3, axis: usize) -> f32 {
+    match axis {
+        0 => v.x,
+        1 => v.y,
+        _ => v.z,
+    }
+}
+
+/// The world-space direction of an OBB's local axis `i`
+fn obb_axis(rotation: &Matrix3, axis: usize) -> Vector3 {
+    Vector3::new(rotation.m[0][axis], rotation.m[1][axis], rotation.m[2][axis])
+}
+
+/// The two axis indices other than `axis`, in ascending order
+fn other_axes(axis: usize) -> (usize, usize) {
+    match axis {
+        0 => (1, 2),
+        1 => (0, 2),
+        _ => (0, 1),
+    }
+}
+
+/// Finds the incident box's face (axis index + outward sign) whose normal is
+/// most anti-parallel to the reference face's outward normal
+fn most_anti_parallel_face(rotation: &Matrix3, reference_normal: Vector3) -> (usize, f32) {
+    let mut best_index = 0;
+    let mut best_sign = 1.0;
+    let mut best_dot = f32::MAX;
+    for axis in 0..3 {
+        let world_axis = obb_axis(rotation, axis);
+        for &sign in &[1.0, -1.0] {
+            let dot = (world_axis * sign).dot(&reference_normal);
+            if dot < best_dot {
+                best_dot = dot;
+                best_index = axis;
+                best_sign = sign;
+            }
+        }
+    }
+    (best_index, best_sign)
+}
+
+/// The 4 world-space corners of the face on `axis`'s `sign` side of `obb`
+fn face_corners(obb: &OBB, axis: usize, sign: f32) -> [Vector3; 4] {
+    let (j, k) = other_axes(axis);
+    let axis_j = obb_axis(&obb.rotation, j);
+    let axis_k = obb_axis(&obb.rotation, k);
+    let half_j = vector_component(obb.half_extents, j);
+    let half_k = vector_component(obb.half_extents, k);
+    let center = obb.position + obb_axis(&obb.rotation, axis) * (sign * vector_component(obb.half_extents, axis));
+
+    [
+        center + axis_j * half_j + axis_k * half_k,
+        center + axis_j * half_j - axis_k * half_k,
+        center - axis_j * half_j - axis_k * half_k,
+        center - axis_j * half_j + axis_k * half_k,
+    ]
+}
+
+/// A Sutherland-Hodgman clip vertex: its 2D position in the reference face's
+/// (j, k) axes, plus the original world-space point those axes parameterize
+#[derive(Debug, Clone, Copy)]
+struct FaceClipVertex {
+    u: f32,
+    v: f32,
+    world: Vector3,
+}
+
+fn lerp_clip_vertex(a: FaceClipVertex, b: FaceClipVertex, da: f32, db: f32) -> FaceClipVertex {
+    let t = da / (da - db);
+    FaceClipVertex {
+        u: a.u + (b.u - a.u) * t,
+        v: a.v + (b.v - a.v) * t,
+        world: a.world + (b.world - a.world) * t,
+    }
+}
+
+/// Clips a polygon against one half-plane, keeping vertices where `signed_distance` is non-negative
+fn clip_face_polygon<F: Fn(&FaceClipVertex) -> f32>(polygon: &[FaceClipVertex], signed_distance: F) -> Vec<FaceClipVertex> {
+    if polygon.is_empty() {
+        return Vec::new();
+    }
+
+    let mut output = Vec::new();
+    for i in 0..polygon.len() {
+        let current = polygon[i];
+        let previous = polygon[(i + polygon.len() - 1) % polygon.len()];
+        let current_d = signed_distance(&current);
+        let previous_d = signed_distance(&previous);
+
+        if current_d >= 0.0 {
+            if previous_d < 0.0 {
+                output.push(lerp_clip_vertex(previous, current, previous_d, current_d));
+            }
+            output.push(current);
+        } else if previous_d >= 0.0 {
+            output.push(lerp_clip_vertex(previous, current, previous_d, current_d));
+        }
+    }
+    output
+}
+
+/// Closest points between segments `p1-q1` and `p2-q2`, following the
+/// clamped-parametric approach from Ericson's *Real-Time Collision Detection* ch. 5.1.2.
+fn closest_points_on_segments(p1: Vector3, q1: Vector3, p2: Vector3, q2: Vector3) -> (Vector3, Vector3) {
+    let d1 = q1 - p1;
+    let d2 = q2 - p2;
+    let r = p1 - p2;
+    let a = d1.dot(&d1);
+    let e = d2.dot(&d2);
+    let f = d2.dot(&r);
+
+    let (s, t) = if a <= 1e-8 && e <= 1e-8 {
+        (0.0, 0.0)
+    } else if a <= 1e-8 {
+        (0.0, (f / e).clamp(0.0, 1.0))
+    } else {
+        let c = d1.dot(&r);
+        if e <= 1e-8 {
+            ((-c / a).clamp(0.0, 1.0), 0.0)
+        } else {
+            let b = d1.dot(&d2);
+            let denom = a * e - b * b;
+            let s = if denom.abs() > 1e-8 { ((b * f - c * e) / denom).clamp(0.0, 1.0) } else { 0.0 };
+            let t = (b * s + f) / e;
+            if t < 0.0 {
+                (((-c) / a).clamp(0.0, 1.0), 0.0)
+            } else if t > 1.0 {
+                (((b - c) / a).clamp(0.0, 1.0), 1.0)
+            } else {
+                (s, t)
+            }
+        }
+    };
+
+    (p1 + d1 * s, p2 + d2 * t)
+}
+
+/// Discards clip points that coincide with an already-kept point (within `eps`), so a
+/// near-degenerate clip that produces duplicate corners doesn't hand the solver two
+/// constraints fighting over the same position.
+fn dedupe_contact_points(points: Vec<ContactPoint>, eps: f32) -> Vec<ContactPoint> {
+    let mut kept: Vec<ContactPoint> = Vec::with_capacity(points.len());
+    for point in points {
+        if !kept.iter().any(|existing| (existing.position - point.position).length() <= eps) {
+            kept.push(point);
+        }
+    }
+    kept
+}
+
+/// Generates a multi-point contact manifold between two OBBs by clipping the
+/// incident face against the reference face's side planes (Sutherland-Hodgman),
+/// after `compute_obb_collision` finds the separating axis and penetration. When the
+/// axis is too poorly aligned with either box's face to clip against (an edge-edge
+/// SAT axis), falls back to a single point at the closest approach between the two
+/// edges whose cross product won the SAT test (on each box, the edge along that axis
+/// lying furthest toward the other box) instead of clipping a degenerate face.
+pub fn generate_obb_manifold(a: &OBB, b: &OBB) -> Option<ContactManifold> {
+    // compute_obb_collision already guarantees `normal` points from A to B and reports
+    // which of the 15 SAT axes won, so there's no need to re-derive face alignment here
+    let (penetration, normal, axis_type) = compute_obb_collision(a, b)?;
+
+    let (reference, incident, ref_axis) = match axis_type {
+        SatAxis::FaceA(axis) => (a, b, axis),
+        SatAxis::FaceB(axis) => (b, a, axis),
+        SatAxis::EdgeEdge(edge_a_axis, edge_b_axis) => {
+            let half_a = vector_component(a.half_extents, edge_a_axis);
+            let half_b = vector_component(b.half_extents, edge_b_axis);
+            let dir_a = obb_axis(&a.rotation, edge_a_axis);
+            let dir_b = obb_axis(&b.rotation, edge_b_axis);
+            // Midpoint of the box's edge along `edge_axis` that lies furthest along `toward`
+            let edge_midpoint = |obb: &OBB, edge_axis: usize, toward: Vector3| -> Vector3 {
+                let (j, k) = other_axes(edge_axis);
+                [j, k].iter().fold(obb.position, |mid, &axis| {
+                    let dir = obb_axis(&obb.rotation, axis);
+                    let sign = if dir.dot(&toward) >= 0.0 { 1.0 } else { -1.0 };
+                    mid + dir * (sign * vector_component(obb.half_extents, axis))
+                })
+            };
+            let mid_a = edge_midpoint(a, edge_a_axis, normal);
+            let mid_b = edge_midpoint(b, edge_b_axis, normal * -1.0);
+            let (point_a, point_b) = closest_points_on_segments(
+                mid_a - dir_a * half_a, mid_a + dir_a * half_a,
+                mid_b - dir_b * half_b, mid_b + dir_b * half_b,
+            );
+            let contact_position = (point_a + point_b) * 0.5;
+            return Some(ContactManifold {
+                normal,
+                points: vec![ContactPoint { position: contact_position, penetration, normal_impulse: 0.0 }],
+            });
+        }
+    };
+    // A's reference face must face toward B, i.e. parallel to `normal` (which always points
+    // A -> B); B's reference face must face back toward A, i.e. anti-parallel to `normal` --
+    // so the two reference sides pick the opposite sign for the same alignment test.
+    let axis_dot_normal = obb_axis(&reference.rotation, ref_axis).dot(&normal);
+    let ref_sign = match axis_type {
+        SatAxis::FaceA(_) => if axis_dot_normal >= 0.0 { 1.0 } else { -1.0 },
+        SatAxis::FaceB(_) => if axis_dot_normal >= 0.0 { -1.0 } else { 1.0 },
+        SatAxis::EdgeEdge(..) => unreachable!("EdgeEdge returns early above"),
+    };
+    let ref_normal = obb_axis(&reference.rotation, ref_axis) * ref_sign;
+
+    let (inc_axis, inc_sign) = most_anti_parallel_face(&incident.rotation, ref_normal);
+    let incident_corners = face_corners(incident, inc_axis, inc_sign);
+
+    let (rj, rk) = other_axes(ref_axis);
+    let axis_j = obb_axis(&reference.rotation, rj);
+    let axis_k = obb_axis(&reference.rotation, rk);
+    let half_j = vector_component(reference.half_extents, rj);
+    let half_k = vector_component(reference.half_extents, rk);
+    let ref_center = reference.position + obb_axis(&reference.rotation, ref_axis) * (ref_sign * vector_component(reference.half_extents, ref_axis));
+
+    let mut polygon: Vec<FaceClipVertex> = incident_corners.iter().map(|&corner| {
+        let relative = corner - ref_center;
+        FaceClipVertex { u: relative.dot(&axis_j), v: relative.dot(&axis_k), world: corner }
+    }).collect();
+
+    polygon = clip_face_polygon(&polygon, |vertex| half_j - vertex.u);
+    polygon = clip_face_polygon(&polygon, |vertex| vertex.u + half_j);
+    polygon = clip_face_polygon(&polygon, |vertex| half_k - vertex.v);
+    polygon = clip_face_polygon(&polygon, |vertex| vertex.v + half_k);
+
+    let points: Vec<ContactPoint> = polygon.iter().filter_map(|vertex| {
+        let penetration = -(vertex.world - ref_center).dot(&ref_normal);
+        if penetration > 0.0 {
+            Some(ContactPoint { position: vertex.world, penetration, normal_impulse: 0.0 })
+        } else {
+            None
+        }
+    }).collect();
+    let points = dedupe_contact_points(points, 1e-4);
+
+    if points.is_empty() {
+        return None;
+    }
+
+    Some(ContactManifold { normal, points })
+}
+
+/// Generates a multi-point contact manifold between an OBB and an AABB by
+/// treating the AABB as an axis-aligned OBB and reusing `generate_obb_manifold`
+pub fn generate_obb_aabb_manifold(obb: &OBB, aabb: &AABB) -> Option<ContactManifold> {
+    let aabb_as_obb = OBB {
+        position: (aabb.min + aabb.max) * 0.5,
+        half_extents: (aabb.max - aabb.min) * 0.5,
+        rotation: Matrix3::identity(),
+    };
+    generate_obb_manifold(obb, &aabb_as_obb)
+}
+
+/// Carries accumulated normal impulses from `previous` over into `new_manifold`
+/// by matching each new point to its nearest old point within `match_distance`,
+/// so the sequential-impulse solver warm-starts from last frame's answer
+/// instead of from zero (which is what makes stacks converge quickly).
+pub fn warm_start_manifold(new_manifold: &mut ContactManifold, previous: &ContactManifold, match_distance: f32) {
+    for point in new_manifold.points.iter_mut() {
+        let nearest = previous.points.iter()
+            .min_by(|a, b| {
+                let da = (a.position - point.position).length();
+                let db = (b.position - point.position).length();
+                da.partial_cmp(&db).unwrap()
+            });
+
+        if let Some(old_point) = nearest {
+            if (old_point.position - point.position).length() <= match_distance {
+                point.normal_impulse = old_point.normal_impulse;
+            }
+        }
+    }
+}
\ No newline at end of file
@@ -2,16 +2,127 @@ use crate::math_utils::{Vector3, Matrix3, Quaternion};
 use crate::aabb::RigidBody;
 use std::f32::consts::PI;
 
+/// Default Baumgarte stabilization factor: the fraction of positional error
+/// fed back as bias velocity each step, mirroring Bullet/Godot's joint defaults
+const DEFAULT_BIAS_FACTOR: f32 = 0.2;
+
+/// Default relaxation factor: scales the final applied impulse, 1.0 meaning
+/// "apply the full corrective impulse"
+const DEFAULT_RELAXATION_FACTOR: f32 = 1.0;
+
 /// Base trait for all physics constraints
 pub trait Constraint {
     /// Prepare for constraint solving
     fn pre_solve(&mut self, dt: f32);
-    
+
     /// Solve the constraint
     fn solve(&mut self, dt: f32);
-    
+
     /// Clean up after constraint solving
     fn post_solve(&mut self);
+
+    /// Zeroes any accumulated warm-start impulses. Call when a constraint is
+    /// newly attached or a body it references is reset/teleported, so the
+    /// next `pre_solve()` doesn't warm-start from a stale impulse.
+    fn reset_accumulators(&mut self) {}
+
+    /// Returns a serializable description of this constraint for
+    /// `PhysicsWorld::to_scene_json`, resolving its body pointers to indices into
+    /// `bodies`. Constraint types that don't yet support scene serialization return
+    /// `None` and are simply omitted from the saved scene.
+    fn describe(&self, _bodies: &[Box<RigidBody>]) -> Option<ConstraintDescriptor> {
+        None
+    }
+}
+
+/// Scene-serializable description of a constraint, referencing its bodies by their
+/// stable index into `PhysicsWorld`'s body list rather than by raw pointer.
+#[derive(Debug, Clone)]
+pub enum ConstraintDescriptor {
+    PointToPoint {
+        body_a: usize,
+        body_b: usize,
+        pivot_a: Vector3,
+        pivot_b: Vector3,
+    },
+}
+
+/// Finds the index of the body a raw constraint pointer refers to, by address
+fn body_index(bodies: &[Box<RigidBody>], body: *mut RigidBody) -> Option<usize> {
+    bodies.iter().position(|b| b.as_ref() as *const RigidBody == body as *const RigidBody)
+}
+
+/// Computes the incremental impulse `λ` for a 3-DOF point constraint (pins
+/// `ra` on `body_a` to `rb` on `body_b`, both offsets from their respective
+/// centers of mass) using the full effective-mass matrix
+/// `K = (inv_mass_a + inv_mass_b)·I − skew(ra)·invI_a·skew(ra) − skew(rb)·invI_b·skew(rb)`,
+/// which accounts for the angular response an off-center pivot induces.
+/// Solves `K·λ = −(Cdot + bias)` where `Cdot = (v_b + w_b × rb) − (v_a + w_a × ra)`
+/// is the relative velocity of the two pivot points and
+/// `bias = (bias_factor / dt) * error` is a Baumgarte term that feeds back a
+/// configurable fraction of the positional `error` (`r_b − r_a`) instead of
+/// driving it out in a single step. Does not apply the impulse; callers
+/// accumulate it for warm-starting and apply it via `apply_point_impulse`.
+fn point_constraint_impulse(
+    body_a: &RigidBody,
+    body_b: &RigidBody,
+    ra: Vector3,
+    rb: Vector3,
+    error: Vector3,
+    bias_factor: f32,
+    dt: f32,
+) -> Vector3 {
+    let inv_mass_a = body_a.inv_mass;
+    let inv_mass_b = body_b.inv_mass;
+    let inv_inertia_a = body_a.inv_inertia_tensor;
+    let inv_inertia_b = body_b.inv_inertia_tensor;
+
+    let cdot = (body_b.velocity + body_b.angular_velocity.cross(&rb))
+        - (body_a.velocity + body_a.angular_velocity.cross(&ra));
+    let bias = error * (bias_factor / dt);
+
+    let skew_ra = Matrix3::skew_symmetric(ra);
+    let skew_rb = Matrix3::skew_symmetric(rb);
+    let k = Matrix3::from_diagonal(inv_mass_a + inv_mass_b)
+        - skew_ra * inv_inertia_a * skew_ra
+        - skew_rb * inv_inertia_b * skew_rb;
+
+    k.inverse().unwrap_or_else(Matrix3::identity) * ((cdot + bias) * -1.0)
+}
+
+/// Applies a point-constraint impulse `impulse` (already computed, or a
+/// warm-started accumulator) to both bodies' linear and, via the lever arm,
+/// angular velocity
+fn apply_point_impulse(body_a: &mut RigidBody, body_b: &mut RigidBody, ra: Vector3, rb: Vector3, impulse: Vector3) {
+    if body_a.inv_mass > 0.0 {
+        body_a.velocity -= impulse * body_a.inv_mass;
+        body_a.angular_velocity -= body_a.inv_inertia_tensor * ra.cross(&impulse);
+    }
+    if body_b.inv_mass > 0.0 {
+        body_b.velocity += impulse * body_b.inv_mass;
+        body_b.angular_velocity += body_b.inv_inertia_tensor * rb.cross(&impulse);
+    }
+}
+
+/// Computes, then applies, the incremental impulse for a point constraint in
+/// one step, accumulating it into `accumulated_impulse` for the next frame's
+/// warm start. The raw impulse is scaled by `relaxation_factor` before being
+/// applied or accumulated, softening the correction for joints tuned less stiff.
+fn solve_point_constraint(
+    body_a: &mut RigidBody,
+    body_b: &mut RigidBody,
+    ra: Vector3,
+    rb: Vector3,
+    error: Vector3,
+    bias_factor: f32,
+    relaxation_factor: f32,
+    dt: f32,
+    accumulated_impulse: &mut Vector3,
+) {
+    let raw_lambda = point_constraint_impulse(body_a, body_b, ra, rb, error, bias_factor, dt);
+    let delta_lambda = raw_lambda * relaxation_factor;
+    *accumulated_impulse += delta_lambda;
+    apply_point_impulse(body_a, body_b, ra, rb, delta_lambda);
 }
 
 /// Point-to-point constraint (ball joint)
@@ -22,6 +133,9 @@ pub struct PointToPointConstraint {
     pivot_b: Vector3,  // Local space pivot point on body B
     r_a: Vector3,      // World space pivot point on body A
     r_b: Vector3,      // World space pivot point on body B
+    accumulated_impulse: Vector3, // Impulse persisted across frames for warm-starting
+    bias_factor: f32, // Baumgarte stabilization factor
+    relaxation_factor: f32, // Scales the applied impulse each solve
 }
 
 impl PointToPointConstraint {
@@ -33,8 +147,23 @@ impl PointToPointConstraint {
             pivot_b,
             r_a: Vector3::zero(),
             r_b: Vector3::zero(),
+            accumulated_impulse: Vector3::zero(),
+            bias_factor: DEFAULT_BIAS_FACTOR,
+            relaxation_factor: DEFAULT_RELAXATION_FACTOR,
         }
     }
+
+    /// Sets the Baumgarte position-correction stiffness (default ~0.2), the
+    /// fraction of positional error fed back as bias velocity each step
+    pub fn set_bias_factor(&mut self, bias_factor: f32) {
+        self.bias_factor = bias_factor;
+    }
+
+    /// Sets the relaxation factor (default 1.0) scaling the final applied
+    /// impulse, for softening an otherwise-stiff joint
+    pub fn set_relaxation_factor(&mut self, relaxation_factor: f32) {
+        self.relaxation_factor = relaxation_factor;
+    }
 }
 
 impl Constraint for PointToPointConstraint {
@@ -47,37 +176,41 @@ impl Constraint for PointToPointConstraint {
             let world_pivot_b = rot_b * self.pivot_b;
             self.r_a = (*self.body_a).position + world_pivot_a;
             self.r_b = (*self.body_b).position + world_pivot_b;
+
+            // Warm start: re-apply last frame's accumulated impulse before solving
+            let ra = self.r_a - (*self.body_a).position;
+            let rb = self.r_b - (*self.body_b).position;
+            apply_point_impulse(&mut *self.body_a, &mut *self.body_b, ra, rb, self.accumulated_impulse);
         }
     }
 
     fn solve(&mut self, dt: f32) {
         unsafe {
-            // Calculate the current error (distance between pivot points)
+            let ra = self.r_a - (*self.body_a).position;
+            let rb = self.r_b - (*self.body_b).position;
             let error = self.r_b - self.r_a;
-            
-            // Calculate the Jacobian
-            let jacobian = error.normalize();
-            
-            // Calculate the effective mass
-            let effective_mass = 1.0 / ((*self.body_a).inv_mass + (*self.body_b).inv_mass);
-            
-            // Calculate the impulse
-            let lambda = -effective_mass * error.length() / dt;
-            
-            // Apply the impulse
-            if (*self.body_a).inv_mass > 0.0 {
-                let impulse = jacobian * (lambda * (*self.body_a).inv_mass);
-                (*self.body_a).velocity += impulse;
-            }
-            if (*self.body_b).inv_mass > 0.0 {
-                let impulse = jacobian * (lambda * (*self.body_b).inv_mass);
-                (*self.body_b).velocity -= impulse;
-            }
+            solve_point_constraint(
+                &mut *self.body_a, &mut *self.body_b, ra, rb, error,
+                self.bias_factor, self.relaxation_factor, dt, &mut self.accumulated_impulse,
+            );
         }
     }
 
     fn post_solve(&mut self) {
-        // Nothing to do here
+        // Impulse is retained across frames for warm-starting, not cleared here
+    }
+
+    fn reset_accumulators(&mut self) {
+        self.accumulated_impulse = Vector3::zero();
+    }
+
+    fn describe(&self, bodies: &[Box<RigidBody>]) -> Option<ConstraintDescriptor> {
+        Some(ConstraintDescriptor::PointToPoint {
+            body_a: body_index(bodies, self.body_a)?,
+            body_b: body_index(bodies, self.body_b)?,
+            pivot_a: self.pivot_a,
+            pivot_b: self.pivot_b,
+        })
     }
 }
 
@@ -94,9 +227,19 @@ pub struct HingeConstraint {
     world_axis_a: Vector3,
     world_axis_b: Vector3,
     target_angle: f32,  // Target rotation angle
-    current_angle: f32, // Current rotation angle
+    current_angle: f32, // Current rotation angle (integrated relative angle about the hinge axis)
     angular_velocity: f32, // Angular velocity for rotating hinges
     is_rotating: bool,    // Whether this is a rotating hinge
+    min_angle: Option<f32>,  // Lower limit on the hinge angle, if any
+    max_angle: Option<f32>,  // Upper limit on the hinge angle, if any
+    enable_motor: bool, // Whether the angular motor is active
+    motor_target_velocity: f32, // Desired angular speed about the axis, when motorized
+    max_motor_impulse: f32, // Bound on the motor's accumulated angular impulse
+    point_accumulated_impulse: Vector3, // Pivot impulse persisted across frames for warm-starting
+    axis_accumulated_impulse: f32, // Axis-alignment impulse persisted across frames for warm-starting
+    motor_accumulated_impulse: f32, // Motor impulse persisted across frames for warm-starting and clamping
+    bias_factor: f32, // Baumgarte stabilization factor
+    relaxation_factor: f32, // Scales the applied impulse each solve
 }
 
 impl HingeConstraint {
@@ -123,6 +266,16 @@ impl HingeConstraint {
             current_angle: 0.0,
             angular_velocity: 0.0,
             is_rotating: false,
+            min_angle: None,
+            max_angle: None,
+            enable_motor: false,
+            motor_target_velocity: 0.0,
+            max_motor_impulse: 0.0,
+            point_accumulated_impulse: Vector3::zero(),
+            axis_accumulated_impulse: 0.0,
+            motor_accumulated_impulse: 0.0,
+            bias_factor: DEFAULT_BIAS_FACTOR,
+            relaxation_factor: DEFAULT_RELAXATION_FACTOR,
         }
     }
 
@@ -147,12 +300,85 @@ impl HingeConstraint {
             current_angle: 0.0,
             angular_velocity,
             is_rotating,
+            min_angle: None,
+            max_angle: None,
+            enable_motor: false,
+            motor_target_velocity: 0.0,
+            max_motor_impulse: 0.0,
+            point_accumulated_impulse: Vector3::zero(),
+            axis_accumulated_impulse: 0.0,
+            motor_accumulated_impulse: 0.0,
+            bias_factor: DEFAULT_BIAS_FACTOR,
+            relaxation_factor: DEFAULT_RELAXATION_FACTOR,
         }
     }
 
     pub fn set_rotation(&mut self, angle: f32) {
         self.target_angle = angle;
     }
+
+    /// Sets the Baumgarte position-correction stiffness (default ~0.2), the
+    /// fraction of positional/angular error fed back as bias velocity each step
+    pub fn set_bias_factor(&mut self, bias_factor: f32) {
+        self.bias_factor = bias_factor;
+    }
+
+    /// Sets the relaxation factor (default 1.0) scaling the final applied
+    /// impulse, for softening an otherwise-stiff joint
+    pub fn set_relaxation_factor(&mut self, relaxation_factor: f32) {
+        self.relaxation_factor = relaxation_factor;
+    }
+
+    /// Sets a lower/upper limit (radians) on the hinge's rotation about its axis
+    pub fn set_angle_limits(&mut self, min_angle: f32, max_angle: f32) {
+        self.min_angle = Some(min_angle);
+        self.max_angle = Some(max_angle);
+    }
+
+    /// Enables the angular motor, driving the relative rotation about the hinge
+    /// axis towards `target_velocity` (rad/s), with `max_impulse` bounding the
+    /// motor's accumulated impulse
+    pub fn set_motor(&mut self, target_velocity: f32, max_impulse: f32) {
+        self.enable_motor = true;
+        self.motor_target_velocity = target_velocity;
+        self.max_motor_impulse = max_impulse;
+    }
+
+    /// Disables the angular motor and clears its accumulated impulse
+    pub fn clear_motor(&mut self) {
+        self.enable_motor = false;
+        self.motor_accumulated_impulse = 0.0;
+    }
+
+    /// Returns the portion of this constraint's internal state needed for
+    /// deterministic snapshot/rollback (target/current angle, motor state)
+    pub fn solver_state(&self) -> HingeSolverState {
+        HingeSolverState {
+            target_angle: self.target_angle,
+            current_angle: self.current_angle,
+            angular_velocity: self.angular_velocity,
+            is_rotating: self.is_rotating,
+        }
+    }
+
+    /// Restores internal solver state captured by `solver_state`, e.g. after a
+    /// rollback-netcode snapshot restore
+    pub fn restore_solver_state(&mut self, state: HingeSolverState) {
+        self.target_angle = state.target_angle;
+        self.current_angle = state.current_angle;
+        self.angular_velocity = state.angular_velocity;
+        self.is_rotating = state.is_rotating;
+    }
+}
+
+/// Snapshot of a `HingeConstraint`'s mutable solver state, for deterministic
+/// rollback (see `HingeConstraint::solver_state`)
+#[derive(Debug, Clone, Copy)]
+pub struct HingeSolverState {
+    pub target_angle: f32,
+    pub current_angle: f32,
+    pub angular_velocity: f32,
+    pub is_rotating: bool,
 }
 
 impl Constraint for HingeConstraint {
@@ -167,49 +393,267 @@ impl Constraint for HingeConstraint {
             self.r_b = (*self.body_b).position + world_pivot_b;
             self.world_axis_a = rot_a * self.axis_a;
             self.world_axis_b = rot_b * self.axis_b;
+
+            // Warm start: re-apply last frame's accumulated impulses before solving
+            let ra = self.r_a - (*self.body_a).position;
+            let rb = self.r_b - (*self.body_b).position;
+            apply_point_impulse(&mut *self.body_a, &mut *self.body_b, ra, rb, self.point_accumulated_impulse);
+
+            let inv_inertia_a = (*self.body_a).inv_inertia_tensor.m[0][0];
+            let inv_inertia_b = (*self.body_b).inv_inertia_tensor.m[0][0];
+            let angular_jacobian = self.world_axis_a.cross(&self.world_axis_b).normalize();
+            if (*self.body_a).inv_mass > 0.0 {
+                (*self.body_a).angular_velocity += angular_jacobian * (self.axis_accumulated_impulse * inv_inertia_a);
+            }
+            if (*self.body_b).inv_mass > 0.0 {
+                (*self.body_b).angular_velocity -= angular_jacobian * (self.axis_accumulated_impulse * inv_inertia_b);
+            }
+
+            if (*self.body_a).inv_mass > 0.0 {
+                (*self.body_a).angular_velocity -= self.world_axis_a * (self.motor_accumulated_impulse * inv_inertia_a);
+            }
+            if (*self.body_b).inv_mass > 0.0 {
+                (*self.body_b).angular_velocity += self.world_axis_a * (self.motor_accumulated_impulse * inv_inertia_b);
+            }
         }
     }
 
     fn solve(&mut self, dt: f32) {
         unsafe {
-            // Solve position constraint (point-to-point)
+            // Solve position constraint (point-to-point), using the full
+            // effective-mass matrix so off-center pivots get a correct response
+            let ra = self.r_a - (*self.body_a).position;
+            let rb = self.r_b - (*self.body_b).position;
             let error = self.r_b - self.r_a;
-            let jacobian = error.normalize();
-            let effective_mass = 1.0 / ((*self.body_a).inv_mass + (*self.body_b).inv_mass);
-            let lambda = -effective_mass * error.length() / dt;
-            
-            if (*self.body_a).inv_mass > 0.0 {
-                let impulse = jacobian * (lambda * (*self.body_a).inv_mass);
-                (*self.body_a).velocity += impulse;
-            }
-            if (*self.body_b).inv_mass > 0.0 {
-                let impulse = jacobian * (lambda * (*self.body_b).inv_mass);
-                (*self.body_b).velocity -= impulse;
-            }
-            
+            solve_point_constraint(
+                &mut *self.body_a, &mut *self.body_b, ra, rb, error,
+                self.bias_factor, self.relaxation_factor, dt, &mut self.point_accumulated_impulse,
+            );
+
             // Solve angular constraint (axis alignment)
             let angular_error = self.world_axis_a.cross(&self.world_axis_b);
             let angular_jacobian = angular_error.normalize();
-            
+
             // Use the diagonal elements of the inertia tensor
             let inv_inertia_a = (*self.body_a).inv_inertia_tensor.m[0][0];
             let inv_inertia_b = (*self.body_b).inv_inertia_tensor.m[0][0];
             let angular_effective_mass = 1.0 / (inv_inertia_a + inv_inertia_b);
-            let angular_lambda = -angular_effective_mass * angular_error.length() / dt;
-            
+            let angular_cdot = angular_jacobian.dot(&((*self.body_b).angular_velocity - (*self.body_a).angular_velocity));
+            let angular_bias = (self.bias_factor / dt) * angular_error.length();
+            let angular_delta_lambda = angular_effective_mass * (angular_cdot + angular_bias) * self.relaxation_factor;
+            self.axis_accumulated_impulse += angular_delta_lambda;
+
             if (*self.body_a).inv_mass > 0.0 {
-                let angular_impulse = angular_jacobian * (angular_lambda * inv_inertia_a);
+                let angular_impulse = angular_jacobian * (angular_delta_lambda * inv_inertia_a);
                 (*self.body_a).angular_velocity += angular_impulse;
             }
             if (*self.body_b).inv_mass > 0.0 {
-                let angular_impulse = angular_jacobian * (angular_lambda * inv_inertia_b);
+                let angular_impulse = angular_jacobian * (angular_delta_lambda * inv_inertia_b);
                 (*self.body_b).angular_velocity -= angular_impulse;
             }
+
+            // Track the relative rotation about the hinge axis, used by the motor/limits
+            let relative_angular_velocity = (*self.body_b).angular_velocity.dot(&self.world_axis_a)
+                - (*self.body_a).angular_velocity.dot(&self.world_axis_a);
+            self.current_angle += relative_angular_velocity * dt;
+
+            // Angular motor: drives the relative spin about the axis towards a target speed,
+            // clamping the *accumulated* motor impulse (not each increment) to [-max, max]
+            if self.enable_motor {
+                let motor_effective_mass = 1.0 / (inv_inertia_a + inv_inertia_b);
+                let motor_delta_lambda = motor_effective_mass * (self.motor_target_velocity - relative_angular_velocity);
+                let previous_impulse = self.motor_accumulated_impulse;
+                self.motor_accumulated_impulse = (previous_impulse + motor_delta_lambda)
+                    .clamp(-self.max_motor_impulse, self.max_motor_impulse);
+                let applied_lambda = self.motor_accumulated_impulse - previous_impulse;
+
+                if (*self.body_a).inv_mass > 0.0 {
+                    (*self.body_a).angular_velocity -= self.world_axis_a * (applied_lambda * inv_inertia_a);
+                }
+                if (*self.body_b).inv_mass > 0.0 {
+                    (*self.body_b).angular_velocity += self.world_axis_a * (applied_lambda * inv_inertia_b);
+                }
+            }
+
+            // Hinge angle limits: push the relative spin back inside [min_angle, max_angle]
+            if let (Some(min_angle), Some(max_angle)) = (self.min_angle, self.max_angle) {
+                let limit_error = if self.current_angle < min_angle {
+                    self.current_angle - min_angle
+                } else if self.current_angle > max_angle {
+                    self.current_angle - max_angle
+                } else {
+                    0.0
+                };
+
+                if limit_error != 0.0 {
+                    let limit_effective_mass = 1.0 / (inv_inertia_a + inv_inertia_b);
+                    let limit_lambda = -limit_effective_mass * (self.bias_factor / dt) * limit_error * self.relaxation_factor;
+
+                    if (*self.body_a).inv_mass > 0.0 {
+                        (*self.body_a).angular_velocity -= self.world_axis_a * (limit_lambda * inv_inertia_a);
+                    }
+                    if (*self.body_b).inv_mass > 0.0 {
+                        (*self.body_b).angular_velocity += self.world_axis_a * (limit_lambda * inv_inertia_b);
+                    }
+                }
+            }
         }
     }
 
     fn post_solve(&mut self) {
-        // Nothing to do here
+        // Impulses are retained across frames for warm-starting, not cleared here
+    }
+
+    fn reset_accumulators(&mut self) {
+        self.point_accumulated_impulse = Vector3::zero();
+        self.axis_accumulated_impulse = 0.0;
+        self.motor_accumulated_impulse = 0.0;
+    }
+}
+
+/// Spherical (ball) joint: constrains two bodies to share a world-space anchor point
+/// while leaving all relative rotation free, with optional swing/twist limits
+pub struct BallConstraint {
+    body_a: *mut RigidBody,
+    body_b: *mut RigidBody,
+    pivot_a: Vector3,
+    pivot_b: Vector3,
+    r_a: Vector3,
+    r_b: Vector3,
+    axis_a: Vector3,       // Local space reference axis on body A, used for swing/twist limits
+    axis_b: Vector3,       // Local space reference axis on body B
+    world_axis_a: Vector3,
+    world_axis_b: Vector3,
+    swing_limit: Option<f32>, // Max angle (radians) the axes may swing apart
+    twist_limit: Option<f32>, // Max angle (radians) of twist about the reference axis
+    point_accumulated_impulse: Vector3, // Anchor impulse persisted across frames for warm-starting
+}
+
+impl BallConstraint {
+    pub fn new(body_a: *mut RigidBody, body_b: *mut RigidBody, pivot_a: Vector3, pivot_b: Vector3) -> Self {
+        Self {
+            body_a,
+            body_b,
+            pivot_a,
+            pivot_b,
+            r_a: Vector3::zero(),
+            r_b: Vector3::zero(),
+            axis_a: Vector3::new(0.0, 1.0, 0.0),
+            axis_b: Vector3::new(0.0, 1.0, 0.0),
+            world_axis_a: Vector3::zero(),
+            world_axis_b: Vector3::zero(),
+            swing_limit: None,
+            twist_limit: None,
+            point_accumulated_impulse: Vector3::zero(),
+        }
+    }
+
+    /// Sets the local-space reference axes used to measure swing/twist limits
+    pub fn set_reference_axes(&mut self, axis_a: Vector3, axis_b: Vector3) {
+        self.axis_a = axis_a;
+        self.axis_b = axis_b;
+    }
+
+    /// Limits how far the reference axes may swing apart (radians)
+    pub fn set_swing_limit(&mut self, angle: f32) {
+        self.swing_limit = Some(angle);
+    }
+
+    /// Limits the twist about the reference axis (radians)
+    pub fn set_twist_limit(&mut self, angle: f32) {
+        self.twist_limit = Some(angle);
+    }
+}
+
+impl Constraint for BallConstraint {
+    fn pre_solve(&mut self, _dt: f32) {
+        unsafe {
+            let rot_a = (*self.body_a).rotation.to_matrix();
+            let rot_b = (*self.body_b).rotation.to_matrix();
+            self.r_a = (*self.body_a).position + rot_a * self.pivot_a;
+            self.r_b = (*self.body_b).position + rot_b * self.pivot_b;
+            self.world_axis_a = rot_a * self.axis_a;
+            self.world_axis_b = rot_b * self.axis_b;
+
+            // Warm start: re-apply last frame's accumulated anchor impulse before solving
+            if (*self.body_a).inv_mass > 0.0 {
+                (*self.body_a).velocity += self.point_accumulated_impulse * (*self.body_a).inv_mass;
+            }
+            if (*self.body_b).inv_mass > 0.0 {
+                (*self.body_b).velocity -= self.point_accumulated_impulse * (*self.body_b).inv_mass;
+            }
+        }
+    }
+
+    fn solve(&mut self, dt: f32) {
+        unsafe {
+            // Positional anchor constraint (point-to-point)
+            let error = self.r_b - self.r_a;
+            let jacobian = error.normalize();
+            let effective_mass = 1.0 / ((*self.body_a).inv_mass + (*self.body_b).inv_mass);
+            let delta_lambda = effective_mass * error.length() / dt;
+            let delta_impulse = jacobian * delta_lambda;
+            self.point_accumulated_impulse += delta_impulse;
+
+            if (*self.body_a).inv_mass > 0.0 {
+                (*self.body_a).velocity += delta_impulse * (*self.body_a).inv_mass;
+            }
+            if (*self.body_b).inv_mass > 0.0 {
+                (*self.body_b).velocity -= delta_impulse * (*self.body_b).inv_mass;
+            }
+
+            let inv_inertia_a = (*self.body_a).inv_inertia_tensor.m[0][0];
+            let inv_inertia_b = (*self.body_b).inv_inertia_tensor.m[0][0];
+
+            // Optional swing limit: clamp the angle between the two reference axes
+            if let Some(swing_limit) = self.swing_limit {
+                let swing_angle = self.world_axis_a.dot(&self.world_axis_b).clamp(-1.0, 1.0).acos();
+                let swing_error = swing_angle - swing_limit;
+                if swing_error > 0.0 {
+                    let swing_axis = self.world_axis_a.cross(&self.world_axis_b).normalize();
+                    let swing_effective_mass = 1.0 / (inv_inertia_a + inv_inertia_b);
+                    let swing_lambda = swing_effective_mass * swing_error / dt;
+
+                    if (*self.body_a).inv_mass > 0.0 {
+                        (*self.body_a).angular_velocity += swing_axis * (swing_lambda * inv_inertia_a);
+                    }
+                    if (*self.body_b).inv_mass > 0.0 {
+                        (*self.body_b).angular_velocity -= swing_axis * (swing_lambda * inv_inertia_b);
+                    }
+                }
+            }
+
+            // Optional twist limit: clamp rotation about the shared reference axis
+            if let Some(twist_limit) = self.twist_limit {
+                // Signed angle about world_axis_a between the two bodies' twist references
+                let twist_axis = self.world_axis_a;
+                let twist_ref_a = (*self.body_a).rotation.to_matrix() * plane_space(self.axis_a).0;
+                let twist_ref_b = (*self.body_b).rotation.to_matrix() * plane_space(self.axis_b).0;
+                let twist_ref_b_proj = (twist_ref_b - twist_axis * twist_ref_b.dot(&twist_axis)).normalize();
+                let twist_angle = twist_axis.dot(&twist_ref_a.cross(&twist_ref_b_proj))
+                    .atan2(twist_ref_a.dot(&twist_ref_b_proj).clamp(-1.0, 1.0));
+                let twist_error = twist_angle.abs() - twist_limit;
+                if twist_error > 0.0 {
+                    let twist_effective_mass = 1.0 / (inv_inertia_a + inv_inertia_b);
+                    let twist_lambda = twist_effective_mass * twist_error * twist_angle.signum() / dt;
+
+                    if (*self.body_a).inv_mass > 0.0 {
+                        (*self.body_a).angular_velocity += twist_axis * (twist_lambda * inv_inertia_a);
+                    }
+                    if (*self.body_b).inv_mass > 0.0 {
+                        (*self.body_b).angular_velocity -= twist_axis * (twist_lambda * inv_inertia_b);
+                    }
+                }
+            }
+        }
+    }
+
+    fn post_solve(&mut self) {
+        // Impulse is retained across frames for warm-starting, not cleared here
+    }
+
+    fn reset_accumulators(&mut self) {
+        self.point_accumulated_impulse = Vector3::zero();
     }
 }
 
@@ -225,6 +669,16 @@ pub struct SliderConstraint {
     r_b: Vector3,
     world_axis_a: Vector3,
     world_axis_b: Vector3,
+    point_accumulated_impulse: Vector3, // Pivot impulse persisted across frames for warm-starting
+    axis_accumulated_impulse: f32, // Axis-alignment impulse persisted across frames for warm-starting
+    bias_factor: f32, // Baumgarte stabilization factor
+    relaxation_factor: f32, // Scales the applied impulse each solve
+    lower_limit: f32, // Lower bound on sliding distance along the axis
+    upper_limit: f32, // Upper bound on sliding distance along the axis; lower_limit > upper_limit means unlimited
+    enable_motor: bool,
+    motor_target_velocity: f32,
+    max_motor_force: f32,
+    motor_accumulated_impulse: f32, // Motor impulse persisted across frames for warm-starting
 }
 
 impl SliderConstraint {
@@ -247,6 +701,64 @@ impl SliderConstraint {
             r_b: Vector3::zero(),
             world_axis_a: Vector3::zero(),
             world_axis_b: Vector3::zero(),
+            point_accumulated_impulse: Vector3::zero(),
+            axis_accumulated_impulse: 0.0,
+            bias_factor: DEFAULT_BIAS_FACTOR,
+            relaxation_factor: DEFAULT_RELAXATION_FACTOR,
+            lower_limit: 1.0,
+            upper_limit: -1.0,
+            enable_motor: false,
+            motor_target_velocity: 0.0,
+            max_motor_force: 0.0,
+            motor_accumulated_impulse: 0.0,
+        }
+    }
+
+    /// Sets the Baumgarte position-correction stiffness (default ~0.2), the
+    /// fraction of positional/angular error fed back as bias velocity each step
+    pub fn set_bias_factor(&mut self, bias_factor: f32) {
+        self.bias_factor = bias_factor;
+    }
+
+    /// Sets the relaxation factor (default 1.0) scaling the final applied
+    /// impulse, for softening an otherwise-stiff joint
+    pub fn set_relaxation_factor(&mut self, relaxation_factor: f32) {
+        self.relaxation_factor = relaxation_factor;
+    }
+
+    /// Limits travel along the slider axis to `[lower, upper]`; sliding is free
+    /// between the two bounds. Passing `lower > upper` leaves the slider unlimited.
+    pub fn set_limits(&mut self, lower: f32, upper: f32) {
+        self.lower_limit = lower;
+        self.upper_limit = upper;
+    }
+
+    /// Enables a motor that drives the sliding velocity toward `target_velocity`,
+    /// clamping the force it may exert to `max_force`
+    pub fn set_motor(&mut self, target_velocity: f32, max_force: f32) {
+        self.enable_motor = true;
+        self.motor_target_velocity = target_velocity;
+        self.max_motor_force = max_force;
+    }
+
+    pub fn clear_motor(&mut self) {
+        self.enable_motor = false;
+        self.motor_accumulated_impulse = 0.0;
+    }
+
+    /// Current displacement along the slider axis, `(r_b - r_a) · axis`
+    pub fn get_position(&self) -> f32 {
+        unsafe { (self.r_b - self.r_a).dot(&self.world_axis_a) }
+    }
+
+    /// Current relative linear velocity along the slider axis
+    pub fn get_linear_velocity(&self) -> f32 {
+        unsafe {
+            let ra = self.r_a - (*self.body_a).position;
+            let rb = self.r_b - (*self.body_b).position;
+            ((*self.body_b).velocity + (*self.body_b).angular_velocity.cross(&rb)
+                - (*self.body_a).velocity - (*self.body_a).angular_velocity.cross(&ra))
+                .dot(&self.world_axis_a)
         }
     }
 }
@@ -263,65 +775,153 @@ impl Constraint for SliderConstraint {
             self.r_b = (*self.body_b).position + world_pivot_b;
             self.world_axis_a = rot_a * self.axis_a;
             self.world_axis_b = rot_b * self.axis_b;
+
+            // Warm start: re-apply last frame's accumulated impulses before solving
+            let ra = self.r_a - (*self.body_a).position;
+            let rb = self.r_b - (*self.body_b).position;
+            apply_point_impulse(&mut *self.body_a, &mut *self.body_b, ra, rb, self.point_accumulated_impulse);
+
+            let inv_inertia_a = (*self.body_a).inv_inertia_tensor.m[0][0];
+            let inv_inertia_b = (*self.body_b).inv_inertia_tensor.m[0][0];
+            let angular_jacobian = self.world_axis_a.cross(&self.world_axis_b).normalize();
+            if (*self.body_a).inv_mass > 0.0 {
+                (*self.body_a).angular_velocity += angular_jacobian * (self.axis_accumulated_impulse * inv_inertia_a);
+            }
+            if (*self.body_b).inv_mass > 0.0 {
+                (*self.body_b).angular_velocity -= angular_jacobian * (self.axis_accumulated_impulse * inv_inertia_b);
+            }
+
+            let motor_impulse = self.world_axis_a * self.motor_accumulated_impulse;
+            if (*self.body_a).inv_mass > 0.0 {
+                (*self.body_a).velocity += motor_impulse * (*self.body_a).inv_mass;
+            }
+            if (*self.body_b).inv_mass > 0.0 {
+                (*self.body_b).velocity -= motor_impulse * (*self.body_b).inv_mass;
+            }
         }
     }
 
     fn solve(&mut self, dt: f32) {
         unsafe {
-            // Solve position constraint (point-to-point)
-            let error = self.r_b - self.r_a;
-            let jacobian = error.normalize();
-            let effective_mass = 1.0 / ((*self.body_a).inv_mass + (*self.body_b).inv_mass);
-            let lambda = -effective_mass * error.length() / dt;
-            
-            if (*self.body_a).inv_mass > 0.0 {
-                let impulse = jacobian * (lambda * (*self.body_a).inv_mass);
-                (*self.body_a).velocity += impulse;
+            // Solve the two translational DOFs perpendicular to the slide axis, each as an
+            // independent scalar constraint (mirroring the per-axis style of the
+            // translational limit/motor solves below). This is deliberately *not* routed
+            // through `solve_point_constraint`: that helper zeroes relative velocity along
+            // all three axes to satisfy Cdot + bias = 0, which would cancel the sliding
+            // velocity the limit and motor below are trying to establish, leaving the
+            // slider unable to slide at all.
+            let ra = self.r_a - (*self.body_a).position;
+            let rb = self.r_b - (*self.body_b).position;
+            let full_error = self.r_b - self.r_a;
+            let (p, q) = plane_space(self.world_axis_a);
+            let relative_velocity = (*self.body_b).velocity + (*self.body_b).angular_velocity.cross(&rb)
+                - (*self.body_a).velocity - (*self.body_a).angular_velocity.cross(&ra);
+            let perp_effective_mass = 1.0 / ((*self.body_a).inv_mass + (*self.body_b).inv_mass);
+            let mut perp_impulse = Vector3::zero();
+            for axis in [p, q] {
+                let cdot = relative_velocity.dot(&axis);
+                let bias = (self.bias_factor / dt) * full_error.dot(&axis);
+                let lambda = -perp_effective_mass * (cdot + bias) * self.relaxation_factor;
+                perp_impulse += axis * lambda;
             }
-            if (*self.body_b).inv_mass > 0.0 {
-                let impulse = jacobian * (lambda * (*self.body_b).inv_mass);
-                (*self.body_b).velocity -= impulse;
-            }
-            
+            self.point_accumulated_impulse += perp_impulse;
+            apply_point_impulse(&mut *self.body_a, &mut *self.body_b, ra, rb, perp_impulse);
+
             // Solve angular constraint (axis alignment)
             let angular_error = self.world_axis_a.cross(&self.world_axis_b);
             let angular_jacobian = angular_error.normalize();
-            
+
             // Use the diagonal elements of the inertia tensor
             let inv_inertia_a = (*self.body_a).inv_inertia_tensor.m[0][0];
             let inv_inertia_b = (*self.body_b).inv_inertia_tensor.m[0][0];
             let angular_effective_mass = 1.0 / (inv_inertia_a + inv_inertia_b);
-            let angular_lambda = -angular_effective_mass * angular_error.length() / dt;
-            
+            let angular_cdot = angular_jacobian.dot(&((*self.body_b).angular_velocity - (*self.body_a).angular_velocity));
+            let angular_bias = (self.bias_factor / dt) * angular_error.length();
+            let angular_delta_lambda = angular_effective_mass * (angular_cdot + angular_bias) * self.relaxation_factor;
+            self.axis_accumulated_impulse += angular_delta_lambda;
+
             if (*self.body_a).inv_mass > 0.0 {
-                let angular_impulse = angular_jacobian * (angular_lambda * inv_inertia_a);
+                let angular_impulse = angular_jacobian * (angular_delta_lambda * inv_inertia_a);
                 (*self.body_a).angular_velocity += angular_impulse;
             }
             if (*self.body_b).inv_mass > 0.0 {
-                let angular_impulse = angular_jacobian * (angular_lambda * inv_inertia_b);
+                let angular_impulse = angular_jacobian * (angular_delta_lambda * inv_inertia_b);
                 (*self.body_b).angular_velocity -= angular_impulse;
             }
-            
-            // Solve translational constraint (sliding along axis)
-            let translational_error = (self.r_b - self.r_a).dot(&self.world_axis_a);
+
+            // Solve translational limits (sliding along axis): free between
+            // lower_limit and upper_limit, restored only once outside that range
             let translational_jacobian = self.world_axis_a;
-            
             let translational_effective_mass = 1.0 / ((*self.body_a).inv_mass + (*self.body_b).inv_mass);
-            let translational_lambda = -translational_effective_mass * translational_error / dt;
-            
-            if (*self.body_a).inv_mass > 0.0 {
-                let translational_impulse = translational_jacobian * (translational_lambda * (*self.body_a).inv_mass);
-                (*self.body_a).velocity += translational_impulse;
+            let translational_cdot = ((*self.body_b).velocity + (*self.body_b).angular_velocity.cross(&rb)
+                - (*self.body_a).velocity - (*self.body_a).angular_velocity.cross(&ra)).dot(&self.world_axis_a);
+
+            if self.lower_limit <= self.upper_limit {
+                let d = (self.r_b - self.r_a).dot(&self.world_axis_a);
+                let limit_error = if d < self.lower_limit {
+                    d - self.lower_limit
+                } else if d > self.upper_limit {
+                    d - self.upper_limit
+                } else {
+                    0.0
+                };
+
+                if limit_error != 0.0 {
+                    let limit_bias = (self.bias_factor / dt) * limit_error;
+                    // Note the positive sign here (as in the angular solve above, unlike the point solve):
+                    // this term is applied with the same body_a += / body_b -= convention as
+                    // the motor below, and driving cdot toward -limit_bias under that
+                    // convention takes +effective_mass*(cdot+bias), not -effective_mass*(...).
+                    // The negated form canceled the wrong direction, amplifying rather than
+                    // damping cdot once the limit engaged and resonating catastrophically
+                    // against an active motor.
+                    let limit_lambda = translational_effective_mass
+                        * (translational_cdot + limit_bias) * self.relaxation_factor;
+
+                    if (*self.body_a).inv_mass > 0.0 {
+                        let limit_impulse = translational_jacobian * (limit_lambda * (*self.body_a).inv_mass);
+                        (*self.body_a).velocity += limit_impulse;
+                    }
+                    if (*self.body_b).inv_mass > 0.0 {
+                        let limit_impulse = translational_jacobian * (limit_lambda * (*self.body_b).inv_mass);
+                        (*self.body_b).velocity -= limit_impulse;
+                    }
+                }
             }
-            if (*self.body_b).inv_mass > 0.0 {
-                let translational_impulse = translational_jacobian * (translational_lambda * (*self.body_b).inv_mass);
-                (*self.body_b).velocity -= translational_impulse;
+
+            // Solve linear motor: drives the sliding velocity toward
+            // motor_target_velocity, accumulating and clamping the running
+            // impulse so the total force never exceeds max_motor_force
+            if self.enable_motor {
+                let motor_cdot = ((*self.body_b).velocity + (*self.body_b).angular_velocity.cross(&rb)
+                    - (*self.body_a).velocity - (*self.body_a).angular_velocity.cross(&ra)).dot(&self.world_axis_a);
+                let motor_lambda = -translational_effective_mass * (self.motor_target_velocity - motor_cdot);
+                let max_motor_impulse = self.max_motor_force * dt;
+                let previous_impulse = self.motor_accumulated_impulse;
+                self.motor_accumulated_impulse = (previous_impulse + motor_lambda)
+                    .clamp(-max_motor_impulse, max_motor_impulse);
+                let applied_lambda = self.motor_accumulated_impulse - previous_impulse;
+
+                if (*self.body_a).inv_mass > 0.0 {
+                    let motor_impulse = translational_jacobian * (applied_lambda * (*self.body_a).inv_mass);
+                    (*self.body_a).velocity += motor_impulse;
+                }
+                if (*self.body_b).inv_mass > 0.0 {
+                    let motor_impulse = translational_jacobian * (applied_lambda * (*self.body_b).inv_mass);
+                    (*self.body_b).velocity -= motor_impulse;
+                }
             }
         }
     }
 
     fn post_solve(&mut self) {
-        // Nothing to do here
+        // Impulses are retained across frames for warm-starting, not cleared here
+    }
+
+    fn reset_accumulators(&mut self) {
+        self.point_accumulated_impulse = Vector3::zero();
+        self.axis_accumulated_impulse = 0.0;
+        self.motor_accumulated_impulse = 0.0;
     }
 }
 
@@ -334,6 +934,9 @@ pub struct DistanceConstraint {
     distance: f32,
     r_a: Vector3,
     r_b: Vector3,
+    accumulated_impulse: f32, // Impulse persisted across frames for warm-starting
+    bias_factor: f32, // Baumgarte stabilization factor
+    relaxation_factor: f32, // Scales the applied impulse each solve
 }
 
 impl DistanceConstraint {
@@ -352,8 +955,23 @@ impl DistanceConstraint {
             distance,
             r_a: Vector3::zero(),
             r_b: Vector3::zero(),
+            accumulated_impulse: 0.0,
+            bias_factor: DEFAULT_BIAS_FACTOR,
+            relaxation_factor: DEFAULT_RELAXATION_FACTOR,
         }
     }
+
+    /// Sets the Baumgarte position-correction stiffness (default ~0.2), the
+    /// fraction of the length error fed back as bias velocity each step
+    pub fn set_bias_factor(&mut self, bias_factor: f32) {
+        self.bias_factor = bias_factor;
+    }
+
+    /// Sets the relaxation factor (default 1.0) scaling the final applied
+    /// impulse, for softening an otherwise-stiff rod
+    pub fn set_relaxation_factor(&mut self, relaxation_factor: f32) {
+        self.relaxation_factor = relaxation_factor;
+    }
 }
 
 impl Constraint for DistanceConstraint {
@@ -366,6 +984,21 @@ impl Constraint for DistanceConstraint {
             let world_pivot_b = rot_b * self.pivot_b;
             self.r_a = (*self.body_a).position + world_pivot_a;
             self.r_b = (*self.body_b).position + world_pivot_b;
+
+            // Warm start: re-apply last frame's accumulated impulse before solving
+            let jacobian = (self.r_b - self.r_a).normalize();
+            let ra = self.r_a - (*self.body_a).position;
+            let rb = self.r_b - (*self.body_b).position;
+            let impulse = jacobian * self.accumulated_impulse;
+
+            if (*self.body_a).inv_mass > 0.0 {
+                (*self.body_a).velocity += impulse * (*self.body_a).inv_mass;
+                (*self.body_a).angular_velocity += (*self.body_a).inv_inertia_tensor * (ra.cross(&jacobian) * self.accumulated_impulse);
+            }
+            if (*self.body_b).inv_mass > 0.0 {
+                (*self.body_b).velocity -= impulse * (*self.body_b).inv_mass;
+                (*self.body_b).angular_velocity -= (*self.body_b).inv_inertia_tensor * (rb.cross(&jacobian) * self.accumulated_impulse);
+            }
         }
     }
 
@@ -374,37 +1007,82 @@ impl Constraint for DistanceConstraint {
             // Calculate the current distance
             let current_vector = self.r_b - self.r_a;
             let current_distance = current_vector.length();
-            
+
             // Calculate the error
             let error = current_distance - self.distance;
-            
+
             // Calculate the Jacobian
             let jacobian = current_vector.normalize();
-            
-            // Calculate the effective mass
-            let effective_mass = 1.0 / ((*self.body_a).inv_mass + (*self.body_b).inv_mass);
-            
+
+            // Lever arms from each body's center of mass to its pivot
+            let ra = self.r_a - (*self.body_a).position;
+            let rb = self.r_b - (*self.body_b).position;
+
+            // Effective mass along the rod's axis, accounting for the angular
+            // response an off-center pivot induces via `(r × n)·invI·(r × n)`
+            let ra_cross_n = ra.cross(&jacobian);
+            let rb_cross_n = rb.cross(&jacobian);
+            let angular_term_a = ra_cross_n.dot(&((*self.body_a).inv_inertia_tensor * ra_cross_n));
+            let angular_term_b = rb_cross_n.dot(&((*self.body_b).inv_inertia_tensor * rb_cross_n));
+            let effective_mass = 1.0
+                / ((*self.body_a).inv_mass + (*self.body_b).inv_mass + angular_term_a + angular_term_b);
+
+            // Relative velocity of the two pivots along the rod's axis, plus a
+            // Baumgarte bias that feeds back a fraction of the length error
+            // each step instead of correcting it in one
+            let cdot = ((*self.body_b).velocity + (*self.body_b).angular_velocity.cross(&rb)
+                - (*self.body_a).velocity - (*self.body_a).angular_velocity.cross(&ra)).dot(&jacobian);
+            let bias = (self.bias_factor / dt) * error;
+
             // Calculate the impulse
-            let lambda = -effective_mass * error / dt;
-            
+            // Positive sign: the impulse acts along +jacobian on body A and -jacobian on body B
+            let delta_lambda = effective_mass * (cdot + bias) * self.relaxation_factor;
+            self.accumulated_impulse += delta_lambda;
+
             // Apply the impulse
             if (*self.body_a).inv_mass > 0.0 {
-                let impulse = jacobian * (lambda * (*self.body_a).inv_mass);
+                let impulse = jacobian * (delta_lambda * (*self.body_a).inv_mass);
                 (*self.body_a).velocity += impulse;
+                (*self.body_a).angular_velocity += (*self.body_a).inv_inertia_tensor * (ra_cross_n * delta_lambda);
             }
             if (*self.body_b).inv_mass > 0.0 {
-                let impulse = jacobian * (lambda * (*self.body_b).inv_mass);
+                let impulse = jacobian * (delta_lambda * (*self.body_b).inv_mass);
                 (*self.body_b).velocity -= impulse;
+                (*self.body_b).angular_velocity -= (*self.body_b).inv_inertia_tensor * (rb_cross_n * delta_lambda);
             }
         }
     }
 
     fn post_solve(&mut self) {
-        // Nothing to do here
+        // Impulse is retained across frames for warm-starting, not cleared here
+    }
+
+    fn reset_accumulators(&mut self) {
+        self.accumulated_impulse = 0.0;
     }
 }
 
 /// Cone-twist constraint (spherical joint with angular limits)
+/// Builds an orthonormal basis `(p, q)` perpendicular to unit vector `n`
+/// (Bullet/Godot's `plane_space` trick), picking whichever of `n`'s components
+/// is smallest to avoid near-degenerate cross products
+fn plane_space(n: Vector3) -> (Vector3, Vector3) {
+    if n.z.abs() > 0.707 {
+        let k = 1.0 / (n.y * n.y + n.z * n.z).sqrt();
+        let p = Vector3::new(0.0, -n.z * k, n.y * k);
+        let q = n.cross(&p);
+        (p, q)
+    } else {
+        let k = 1.0 / (n.x * n.x + n.y * n.y).sqrt();
+        let p = Vector3::new(-n.y * k, n.x * k, 0.0);
+        let q = n.cross(&p);
+        (p, q)
+    }
+}
+
+/// Cone-twist joint (spherical joint with swing/twist limits). `axis_a`/`axis_b`
+/// are each body's local-space reference axis; when aligned, the bodies sit at
+/// the center of the cone with zero twist
 pub struct ConeTwistConstraint {
     body_a: *mut RigidBody,
     body_b: *mut RigidBody,
@@ -416,9 +1094,14 @@ pub struct ConeTwistConstraint {
     r_b: Vector3,
     world_axis_a: Vector3,
     world_axis_b: Vector3,
+    world_twist_ref_a: Vector3, // Twist reference vector, perpendicular to axis_a
+    world_twist_ref_b: Vector3, // Twist reference vector, perpendicular to axis_b
     swing_span1: f32,  // Angular limit in one direction
     swing_span2: f32,  // Angular limit in perpendicular direction
     twist_span: f32,   // Angular limit around the axis
+    point_accumulated_impulse: Vector3, // Pivot impulse persisted across frames for warm-starting
+    bias_factor: f32, // Baumgarte stabilization factor
+    relaxation_factor: f32, // Scales the applied impulse each solve
 }
 
 impl ConeTwistConstraint {
@@ -441,9 +1124,14 @@ impl ConeTwistConstraint {
             r_b: Vector3::zero(),
             world_axis_a: Vector3::zero(),
             world_axis_b: Vector3::zero(),
+            world_twist_ref_a: Vector3::zero(),
+            world_twist_ref_b: Vector3::zero(),
             swing_span1: PI,
             swing_span2: PI,
             twist_span: PI,
+            point_accumulated_impulse: Vector3::zero(),
+            bias_factor: DEFAULT_BIAS_FACTOR,
+            relaxation_factor: DEFAULT_RELAXATION_FACTOR,
         }
     }
 
@@ -458,6 +1146,18 @@ impl ConeTwistConstraint {
     pub fn set_twist_span(&mut self, angle: f32) {
         self.twist_span = angle;
     }
+
+    /// Sets the Baumgarte position-correction stiffness (default ~0.2), the
+    /// fraction of positional/angular error fed back as bias velocity each step
+    pub fn set_bias_factor(&mut self, bias_factor: f32) {
+        self.bias_factor = bias_factor;
+    }
+
+    /// Sets the relaxation factor (default 1.0) scaling the final applied
+    /// impulse, for softening an otherwise-stiff joint
+    pub fn set_relaxation_factor(&mut self, relaxation_factor: f32) {
+        self.relaxation_factor = relaxation_factor;
+    }
 }
 
 impl Constraint for ConeTwistConstraint {
@@ -472,64 +1172,91 @@ impl Constraint for ConeTwistConstraint {
             self.r_b = (*self.body_b).position + world_pivot_b;
             self.world_axis_a = rot_a * self.axis_a;
             self.world_axis_b = rot_b * self.axis_b;
+            self.world_twist_ref_a = rot_a * plane_space(self.axis_a).0;
+            self.world_twist_ref_b = rot_b * plane_space(self.axis_b).0;
+
+            // Warm start: re-apply last frame's accumulated pivot impulse before solving
+            let ra = self.r_a - (*self.body_a).position;
+            let rb = self.r_b - (*self.body_b).position;
+            apply_point_impulse(&mut *self.body_a, &mut *self.body_b, ra, rb, self.point_accumulated_impulse);
         }
     }
 
     fn solve(&mut self, dt: f32) {
         unsafe {
-            // Solve position constraint (point-to-point)
+            // Solve position constraint (point-to-point), using the full
+            // effective-mass matrix so off-center pivots get a correct response
+            let ra = self.r_a - (*self.body_a).position;
+            let rb = self.r_b - (*self.body_b).position;
             let error = self.r_b - self.r_a;
-            let jacobian = error.normalize();
-            let effective_mass = 1.0 / ((*self.body_a).inv_mass + (*self.body_b).inv_mass);
-            let lambda = -effective_mass * error.length() / dt;
-            
-            if (*self.body_a).inv_mass > 0.0 {
-                let impulse = jacobian * (lambda * (*self.body_a).inv_mass);
-                (*self.body_a).velocity += impulse;
-            }
-            if (*self.body_b).inv_mass > 0.0 {
-                let impulse = jacobian * (lambda * (*self.body_b).inv_mass);
-                (*self.body_b).velocity -= impulse;
-            }
-            
-            // Solve swing limits
-            let swing_angle = self.world_axis_a.dot(&self.world_axis_b).acos();
-            if swing_angle > 0.0 {
-                let swing_axis = self.world_axis_a.cross(&self.world_axis_b).normalize();
-                let swing_error = swing_angle - self.swing_span1.min(self.swing_span2);
-                
-                if swing_error > 0.0 {
-                    // Use the diagonal elements of the inertia tensor
-                    let inv_inertia_a = (*self.body_a).inv_inertia_tensor.m[0][0];
-                    let inv_inertia_b = (*self.body_b).inv_inertia_tensor.m[0][0];
-                    let swing_effective_mass = 1.0 / (inv_inertia_a + inv_inertia_b);
-                    let swing_lambda = -swing_effective_mass * swing_error / dt;
-                    
-                    if (*self.body_a).inv_mass > 0.0 {
-                        let swing_impulse = swing_axis * (swing_lambda * inv_inertia_a);
-                        (*self.body_a).angular_velocity += swing_impulse;
-                    }
-                    if (*self.body_b).inv_mass > 0.0 {
-                        let swing_impulse = swing_axis * (swing_lambda * inv_inertia_b);
-                        (*self.body_b).angular_velocity -= swing_impulse;
-                    }
+            solve_point_constraint(
+                &mut *self.body_a, &mut *self.body_b, ra, rb, error,
+                self.bias_factor, self.relaxation_factor, dt, &mut self.point_accumulated_impulse,
+            );
+
+            // Solve swing limits: the cone formed by swing_span1/swing_span2 is an
+            // ellipse, not a circle, so the allowed swing angle varies with the
+            // azimuth `phi` around world_axis_a
+            let n = self.world_axis_a;
+            let (p, q) = plane_space(n);
+            let swing_p = self.world_axis_b.dot(&p);
+            let swing_q = self.world_axis_b.dot(&q);
+            let swing_n = self.world_axis_b.dot(&n).clamp(-1.0, 1.0);
+            let in_plane_len = (swing_p * swing_p + swing_q * swing_q).sqrt();
+            let swing_angle = in_plane_len.atan2(swing_n);
+            let phi = swing_q.atan2(swing_p);
+            let max_swing_at_phi = 1.0 / ((phi.cos() / self.swing_span1).powi(2)
+                + (phi.sin() / self.swing_span2).powi(2)).sqrt();
+            let swing_error = swing_angle - max_swing_at_phi;
+
+            if swing_error > 0.0 && in_plane_len > 1e-6 {
+                let swing_dir = (p * swing_p + q * swing_q).normalize();
+                let swing_axis = n.cross(&swing_dir).normalize();
+                // Use the diagonal elements of the inertia tensor
+                let inv_inertia_a = (*self.body_a).inv_inertia_tensor.m[0][0];
+                let inv_inertia_b = (*self.body_b).inv_inertia_tensor.m[0][0];
+                let swing_effective_mass = 1.0 / (inv_inertia_a + inv_inertia_b);
+                // Must include the velocity term (swing_cdot), not just the positional
+                // bias: this block is applied with the same body_a += / body_b -=
+                // convention as the angular axis-alignment solve above, where driving
+                // cdot toward -bias takes +effective_mass*(cdot+bias). A bias-only,
+                // wrong-signed impulse never damps, so it keeps adding swing velocity
+                // every frame the cone is exceeded instead of arresting it.
+                let swing_cdot = swing_axis.dot(&((*self.body_b).angular_velocity - (*self.body_a).angular_velocity));
+                let swing_bias = (self.bias_factor / dt) * swing_error;
+                let swing_lambda = swing_effective_mass * (swing_cdot + swing_bias) * self.relaxation_factor;
+
+                if (*self.body_a).inv_mass > 0.0 {
+                    let swing_impulse = swing_axis * (swing_lambda * inv_inertia_a);
+                    (*self.body_a).angular_velocity += swing_impulse;
+                }
+                if (*self.body_b).inv_mass > 0.0 {
+                    let swing_impulse = swing_axis * (swing_lambda * inv_inertia_b);
+                    (*self.body_b).angular_velocity -= swing_impulse;
                 }
             }
-            
-            // Solve twist limits
-            let twist_angle = self.world_axis_a.cross(&self.world_axis_b).length().atan2(
-                self.world_axis_a.dot(&self.world_axis_b)
-            );
-            let twist_error = twist_angle.abs() - self.twist_span;
-            
-            if twist_error > 0.0 {
-                let twist_axis = self.world_axis_a;
+
+            // Solve twist limits: project body b's twist reference onto the plane
+            // perpendicular to world_axis_a and measure the signed angle to body
+            // a's twist reference, so over-rotation in either direction is caught
+            let twist_ref_b_proj = (self.world_twist_ref_b - n * self.world_twist_ref_b.dot(&n)).normalize();
+            let cos_twist = self.world_twist_ref_a.dot(&twist_ref_b_proj).clamp(-1.0, 1.0);
+            let sin_twist = n.dot(&self.world_twist_ref_a.cross(&twist_ref_b_proj));
+            let twist_angle = sin_twist.atan2(cos_twist);
+
+            if twist_angle.abs() > self.twist_span {
+                let twist_error = twist_angle - twist_angle.signum() * self.twist_span;
+                let twist_axis = n;
                 // Use the diagonal elements of the inertia tensor
                 let inv_inertia_a = (*self.body_a).inv_inertia_tensor.m[0][0];
                 let inv_inertia_b = (*self.body_b).inv_inertia_tensor.m[0][0];
                 let twist_effective_mass = 1.0 / (inv_inertia_a + inv_inertia_b);
-                let twist_lambda = -twist_effective_mass * twist_error / dt;
-                
+                // Same fix as the swing block above: include the velocity term and use
+                // the sign that matches this block's body_a += / body_b -= convention.
+                let twist_cdot = twist_axis.dot(&((*self.body_b).angular_velocity - (*self.body_a).angular_velocity));
+                let twist_bias = (self.bias_factor / dt) * twist_error;
+                let twist_lambda = twist_effective_mass * (twist_cdot + twist_bias) * self.relaxation_factor;
+
                 if (*self.body_a).inv_mass > 0.0 {
                     let twist_impulse = twist_axis * (twist_lambda * inv_inertia_a);
                     (*self.body_a).angular_velocity += twist_impulse;
@@ -543,6 +1270,473 @@ impl Constraint for ConeTwistConstraint {
     }
 
     fn post_solve(&mut self) {
-        // Nothing to do here
+        // Impulse is retained across frames for warm-starting, not cleared here
+    }
+
+    fn reset_accumulators(&mut self) {
+        self.point_accumulated_impulse = Vector3::zero();
+    }
+} 
+/// Returns the `axis`-th component of `v` (0 = x, 1 = y, 2 = z)
+fn vector_component(v: Vector3, axis: usize) -> f32 {
+    match axis {
+        0 => v.x,
+        1 => v.y,
+        _ => v.z,
+    }
+}
+
+/// Decomposes a rotation matrix into Z-Y-X Euler angles (i.e. `R = Rz(z)·Ry(y)·Rx(x)`),
+/// returned as `(x, y, z)` in a `Vector3`. The Y angle is clamped away from
+/// `±π/2` to avoid the gimbal-lock singularity where `x` and `z` become degenerate.
+fn decompose_euler_zyx(r: &Matrix3) -> Vector3 {
+    let m = r.m;
+    let sin_y = (-m[2][0]).clamp(-0.9999, 0.9999);
+    let y = sin_y.asin();
+    let x = m[2][1].atan2(m[2][2]);
+    let z = m[1][0].atan2(m[0][0]);
+    Vector3::new(x, y, z)
+}
+
+/// Per-degree-of-freedom limit and optional motor used by `Generic6DOFConstraint`.
+/// `lower > upper` means the DOF is free, `lower == upper` means it is locked to
+/// that value, and any other `lower <= upper` means it is limited to that range.
+#[derive(Debug, Clone, Copy)]
+pub struct DofLimit {
+    pub lower: f32,
+    pub upper: f32,
+    pub motor_target_velocity: Option<f32>,
+    pub motor_max_force: f32,
+}
+
+impl DofLimit {
+    pub fn free() -> Self {
+        Self { lower: 1.0, upper: -1.0, motor_target_velocity: None, motor_max_force: 0.0 }
+    }
+
+    pub fn locked(value: f32) -> Self {
+        Self { lower: value, upper: value, motor_target_velocity: None, motor_max_force: 0.0 }
+    }
+
+    pub fn limited(lower: f32, upper: f32) -> Self {
+        Self { lower, upper, motor_target_velocity: None, motor_max_force: 0.0 }
+    }
+
+    pub fn is_free(&self) -> bool {
+        self.lower > self.upper
+    }
+
+    pub fn is_locked(&self) -> bool {
+        (self.lower - self.upper).abs() < 1e-6
+    }
+
+    /// Enables a motor on this DOF, driving it towards `target_velocity`
+    /// bounded each solve by `max_force`
+    pub fn set_motor(&mut self, target_velocity: f32, max_force: f32) {
+        self.motor_target_velocity = Some(target_velocity);
+        self.motor_max_force = max_force;
+    }
+
+    /// Disables the motor on this DOF
+    pub fn clear_motor(&mut self) {
+        self.motor_target_velocity = None;
+    }
+}
+
+/// Six-degree-of-freedom constraint (modeled on Bullet's `btGeneric6DofConstraint`):
+/// independently locks, limits, frees, or motorizes each of the three translational
+/// and three rotational degrees of freedom between two bodies. A single instance can
+/// emulate a point-to-point, hinge, slider, or cone-twist joint depending on how its
+/// per-DOF limits are configured.
+pub struct Generic6DOFConstraint {
+    body_a: *mut RigidBody,
+    body_b: *mut RigidBody,
+    frame_a: Matrix3, // Local basis on body A; its columns are the constraint axes
+    frame_b: Matrix3, // Local basis on body B
+    pivot_a: Vector3,
+    pivot_b: Vector3,
+    r_a: Vector3,
+    r_b: Vector3,
+    world_frame_a: Matrix3,
+    world_frame_b: Matrix3,
+    current_euler: Vector3, // Relative rotation of frame_b in frame_a, as Z-Y-X Euler angles
+    linear_limits: [DofLimit; 3],  // Translation along frame_a's x/y/z axes
+    angular_limits: [DofLimit; 3], // Rotation about frame_a's x/y/z axes (Z-Y-X order)
+    bias_factor: f32,
+    relaxation_factor: f32,
+}
+
+impl Generic6DOFConstraint {
+    pub fn new(
+        body_a: *mut RigidBody,
+        body_b: *mut RigidBody,
+        frame_a: Matrix3,
+        frame_b: Matrix3,
+        pivot_a: Vector3,
+        pivot_b: Vector3,
+    ) -> Self {
+        Self {
+            body_a,
+            body_b,
+            frame_a,
+            frame_b,
+            pivot_a,
+            pivot_b,
+            r_a: Vector3::zero(),
+            r_b: Vector3::zero(),
+            world_frame_a: Matrix3::identity(),
+            world_frame_b: Matrix3::identity(),
+            current_euler: Vector3::zero(),
+            linear_limits: [DofLimit::free(); 3],
+            angular_limits: [DofLimit::free(); 3],
+            bias_factor: DEFAULT_BIAS_FACTOR,
+            relaxation_factor: DEFAULT_RELAXATION_FACTOR,
+        }
+    }
+
+    /// Sets the limit/motor state of a translational DOF (0 = x, 1 = y, 2 = z of `frame_a`)
+    pub fn set_linear_limit(&mut self, axis: usize, limit: DofLimit) {
+        self.linear_limits[axis] = limit;
+    }
+
+    /// Sets the limit/motor state of a rotational DOF (0 = x, 1 = y, 2 = z, Z-Y-X order)
+    pub fn set_angular_limit(&mut self, axis: usize, limit: DofLimit) {
+        self.angular_limits[axis] = limit;
+    }
+
+    pub fn set_bias_factor(&mut self, bias_factor: f32) {
+        self.bias_factor = bias_factor;
+    }
+
+    pub fn set_relaxation_factor(&mut self, relaxation_factor: f32) {
+        self.relaxation_factor = relaxation_factor;
+    }
+
+    /// Solves a single scalar DOF along/about `axis`, given its current value
+    /// `current`, relative velocity `cdot` along that axis, and `effective_mass`.
+    /// Applies any configured motor first, then a Baumgarte-stabilized limit/lock
+    /// impulse if `limit` isn't free. `apply` does the actual velocity update,
+    /// since linear and angular DOFs affect the bodies differently.
+    fn solve_dof(
+        limit: &DofLimit,
+        current: f32,
+        cdot: f32,
+        effective_mass: f32,
+        bias_factor: f32,
+        relaxation_factor: f32,
+        dt: f32,
+        mut apply: impl FnMut(f32),
+    ) {
+        if let Some(target_velocity) = limit.motor_target_velocity {
+            let max_impulse = limit.motor_max_force * dt;
+            let motor_lambda = (effective_mass * (target_velocity - cdot)).clamp(-max_impulse, max_impulse);
+            apply(motor_lambda);
+        }
+
+        if limit.is_free() {
+            return;
+        }
+
+        let error = if limit.is_locked() {
+            current - limit.lower
+        } else if current < limit.lower {
+            current - limit.lower
+        } else if current > limit.upper {
+            current - limit.upper
+        } else {
+            0.0
+        };
+
+        if error != 0.0 {
+            let bias = (bias_factor / dt) * error;
+            let lambda = -effective_mass * (cdot + bias) * relaxation_factor;
+            apply(lambda);
+        }
+    }
+}
+
+impl Constraint for Generic6DOFConstraint {
+    fn pre_solve(&mut self, _dt: f32) {
+        unsafe {
+            let rot_a = (*self.body_a).rotation.to_matrix();
+            let rot_b = (*self.body_b).rotation.to_matrix();
+            self.world_frame_a = rot_a * self.frame_a;
+            self.world_frame_b = rot_b * self.frame_b;
+            self.r_a = (*self.body_a).position + rot_a * self.pivot_a;
+            self.r_b = (*self.body_b).position + rot_b * self.pivot_b;
+
+            let relative_rotation = self.world_frame_a.transpose() * self.world_frame_b;
+            self.current_euler = decompose_euler_zyx(&relative_rotation);
+        }
+    }
+
+    fn solve(&mut self, dt: f32) {
+        unsafe {
+            let ra = self.r_a - (*self.body_a).position;
+            let rb = self.r_b - (*self.body_b).position;
+            let separation = self.r_b - self.r_a;
+
+            for axis in 0..3 {
+                let limit = self.linear_limits[axis];
+                if limit.is_free() && limit.motor_target_velocity.is_none() {
+                    continue;
+                }
+
+                let world_axis = self.world_frame_a.get_column(axis);
+                let current = separation.dot(&world_axis);
+                let cdot = ((*self.body_b).velocity + (*self.body_b).angular_velocity.cross(&rb)
+                    - (*self.body_a).velocity - (*self.body_a).angular_velocity.cross(&ra)).dot(&world_axis);
+                let effective_mass = 1.0 / ((*self.body_a).inv_mass + (*self.body_b).inv_mass);
+
+                Self::solve_dof(&limit, current, cdot, effective_mass, self.bias_factor, self.relaxation_factor, dt, |lambda| {
+                    let impulse = world_axis * lambda;
+                    if (*self.body_a).inv_mass > 0.0 {
+                        (*self.body_a).velocity -= impulse * (*self.body_a).inv_mass;
+                        (*self.body_a).angular_velocity -= (*self.body_a).inv_inertia_tensor * ra.cross(&impulse);
+                    }
+                    if (*self.body_b).inv_mass > 0.0 {
+                        (*self.body_b).velocity += impulse * (*self.body_b).inv_mass;
+                        (*self.body_b).angular_velocity += (*self.body_b).inv_inertia_tensor * rb.cross(&impulse);
+                    }
+                });
+            }
+
+            let inv_inertia_a = (*self.body_a).inv_inertia_tensor.m[0][0];
+            let inv_inertia_b = (*self.body_b).inv_inertia_tensor.m[0][0];
+            let relative_angular_velocity = (*self.body_b).angular_velocity - (*self.body_a).angular_velocity;
+
+            for axis in 0..3 {
+                let limit = self.angular_limits[axis];
+                if limit.is_free() && limit.motor_target_velocity.is_none() {
+                    continue;
+                }
+
+                let world_axis = self.world_frame_a.get_column(axis);
+                let current = vector_component(self.current_euler, axis);
+                let cdot = world_axis.dot(&relative_angular_velocity);
+                let effective_mass = 1.0 / (inv_inertia_a + inv_inertia_b);
+
+                Self::solve_dof(&limit, current, cdot, effective_mass, self.bias_factor, self.relaxation_factor, dt, |lambda| {
+                    if (*self.body_a).inv_mass > 0.0 {
+                        (*self.body_a).angular_velocity -= world_axis * (lambda * inv_inertia_a);
+                    }
+                    if (*self.body_b).inv_mass > 0.0 {
+                        (*self.body_b).angular_velocity += world_axis * (lambda * inv_inertia_b);
+                    }
+                });
+            }
+        }
+    }
+
+    fn post_solve(&mut self) {
+        // No accumulated impulses: each DOF is solved fresh from the current
+        // position/velocity error every step, rather than warm-started
+    }
+}
+
+/// Universal joint (as in nphysics/Bullet's `btUniversalConstraint`): permits
+/// exactly two independent relative rotations about two orthogonal axes while
+/// locking translation (at a shared world anchor) and the third rotation.
+/// Ideal for drive shafts, cardan joints, and steering linkages.
+pub struct UniversalJoint {
+    body_a: *mut RigidBody,
+    body_b: *mut RigidBody,
+    local_pivot_a: Vector3,
+    local_pivot_b: Vector3,
+    local_frame_a: Matrix3, // Columns (x, y, z) = (locked axis, axis2, axis1), relative to body A's orientation
+    local_frame_b: Matrix3, // Same frame, relative to body B's orientation
+    r_a: Vector3,
+    r_b: Vector3,
+    world_frame_a: Matrix3,
+    world_frame_b: Matrix3,
+    swing_angle1: f32, // Current relative rotation about axis1 (z)
+    swing_angle2: f32, // Current relative rotation about axis2 (y)
+    limit1: Option<(f32, f32)>, // Optional (min, max) on the axis1 swing angle
+    limit2: Option<(f32, f32)>, // Optional (min, max) on the axis2 swing angle
+    point_accumulated_impulse: Vector3, // Anchor impulse persisted across frames for warm-starting
+    bias_factor: f32,
+    relaxation_factor: f32,
+}
+
+impl UniversalJoint {
+    /// Builds the joint at world-space `anchor`, permitting independent rotation
+    /// about `axis1` and `axis2` (which must be orthogonal) while locking
+    /// translation and the rotation about their cross product
+    ///
+    /// # Safety
+    /// `body_a` and `body_b` must be valid, non-null pointers to live `RigidBody`s
+    /// for the duration of this call; their current position/rotation is read here.
+    pub unsafe fn new(body_a: *mut RigidBody, body_b: *mut RigidBody, anchor: Vector3, axis1: Vector3, axis2: Vector3) -> Self {
+        let rot_a = (*body_a).rotation.to_matrix();
+        let rot_b = (*body_b).rotation.to_matrix();
+
+        let z = axis1.normalize();
+        let y = axis2.normalize();
+        let x = y.cross(&z).normalize();
+        let world_frame = Matrix3::from_rows(x, y, z).transpose();
+
+        Self {
+            body_a,
+            body_b,
+            local_pivot_a: rot_a.transpose() * (anchor - (*body_a).position),
+            local_pivot_b: rot_b.transpose() * (anchor - (*body_b).position),
+            local_frame_a: rot_a.transpose() * world_frame,
+            local_frame_b: rot_b.transpose() * world_frame,
+            r_a: Vector3::zero(),
+            r_b: Vector3::zero(),
+            world_frame_a: Matrix3::identity(),
+            world_frame_b: Matrix3::identity(),
+            swing_angle1: 0.0,
+            swing_angle2: 0.0,
+            limit1: None,
+            limit2: None,
+            point_accumulated_impulse: Vector3::zero(),
+            bias_factor: DEFAULT_BIAS_FACTOR,
+            relaxation_factor: DEFAULT_RELAXATION_FACTOR,
+        }
+    }
+
+    /// The current relative rotation (radians) about `axis1`
+    pub fn swing_angle1(&self) -> f32 {
+        self.swing_angle1
     }
-} 
\ No newline at end of file
+
+    /// The current relative rotation (radians) about `axis2`
+    pub fn swing_angle2(&self) -> f32 {
+        self.swing_angle2
+    }
+
+    /// Limits the swing about `axis1` to `[min_angle, max_angle]`
+    pub fn set_limit1(&mut self, min_angle: f32, max_angle: f32) {
+        self.limit1 = Some((min_angle, max_angle));
+    }
+
+    /// Limits the swing about `axis2` to `[min_angle, max_angle]`
+    pub fn set_limit2(&mut self, min_angle: f32, max_angle: f32) {
+        self.limit2 = Some((min_angle, max_angle));
+    }
+
+    pub fn set_bias_factor(&mut self, bias_factor: f32) {
+        self.bias_factor = bias_factor;
+    }
+
+    pub fn set_relaxation_factor(&mut self, relaxation_factor: f32) {
+        self.relaxation_factor = relaxation_factor;
+    }
+}
+
+impl Constraint for UniversalJoint {
+    fn pre_solve(&mut self, _dt: f32) {
+        unsafe {
+            let rot_a = (*self.body_a).rotation.to_matrix();
+            let rot_b = (*self.body_b).rotation.to_matrix();
+            self.r_a = (*self.body_a).position + rot_a * self.local_pivot_a;
+            self.r_b = (*self.body_b).position + rot_b * self.local_pivot_b;
+            self.world_frame_a = rot_a * self.local_frame_a;
+            self.world_frame_b = rot_b * self.local_frame_b;
+
+            // The locked axis (x, column 0) of frame B expressed in frame A's
+            // basis: its x/y/z components tell us how far B has swung about
+            // A's z-axis (axis1) and y-axis (axis2) respectively
+            let x_b = self.world_frame_b.get_column(0);
+            let x_b_in_a = Vector3::new(
+                x_b.dot(&self.world_frame_a.get_column(0)),
+                x_b.dot(&self.world_frame_a.get_column(1)),
+                x_b.dot(&self.world_frame_a.get_column(2)),
+            );
+            self.swing_angle1 = x_b_in_a.y.atan2(x_b_in_a.x);
+            self.swing_angle2 = (-x_b_in_a.z).atan2(x_b_in_a.x);
+
+            // Warm start: re-apply last frame's accumulated anchor impulse before solving
+            let ra = self.r_a - (*self.body_a).position;
+            let rb = self.r_b - (*self.body_b).position;
+            apply_point_impulse(&mut *self.body_a, &mut *self.body_b, ra, rb, self.point_accumulated_impulse);
+        }
+    }
+
+    fn solve(&mut self, dt: f32) {
+        unsafe {
+            let ra = self.r_a - (*self.body_a).position;
+            let rb = self.r_b - (*self.body_b).position;
+            let error = self.r_b - self.r_a;
+            solve_point_constraint(
+                &mut *self.body_a, &mut *self.body_b, ra, rb, error,
+                self.bias_factor, self.relaxation_factor, dt, &mut self.point_accumulated_impulse,
+            );
+
+            let inv_inertia_a = (*self.body_a).inv_inertia_tensor.m[0][0];
+            let inv_inertia_b = (*self.body_b).inv_inertia_tensor.m[0][0];
+            let effective_mass = 1.0 / (inv_inertia_a + inv_inertia_b);
+
+            // Lock the forbidden axis (x): keep axis1 on A perpendicular to axis2 on B,
+            // driving C = axis1_a · axis2_b to zero while rotation about either axis stays free
+            let axis1_a = self.world_frame_a.get_column(2);
+            let axis2_b = self.world_frame_b.get_column(1);
+            let angular_jacobian = axis2_b.cross(&axis1_a);
+            let angular_cdot = angular_jacobian.dot(&((*self.body_b).angular_velocity - (*self.body_a).angular_velocity));
+            let angular_bias = (self.bias_factor / dt) * axis1_a.dot(&axis2_b);
+            let angular_lambda = -effective_mass * (angular_cdot + angular_bias) * self.relaxation_factor;
+
+            if (*self.body_a).inv_mass > 0.0 {
+                (*self.body_a).angular_velocity -= angular_jacobian * (angular_lambda * inv_inertia_a);
+            }
+            if (*self.body_b).inv_mass > 0.0 {
+                (*self.body_b).angular_velocity += angular_jacobian * (angular_lambda * inv_inertia_b);
+            }
+
+            // Optional limit on the axis1 (z) swing
+            if let Some((min_angle, max_angle)) = self.limit1 {
+                let limit_error = if self.swing_angle1 < min_angle {
+                    self.swing_angle1 - min_angle
+                } else if self.swing_angle1 > max_angle {
+                    self.swing_angle1 - max_angle
+                } else {
+                    0.0
+                };
+
+                if limit_error != 0.0 {
+                    let axis = self.world_frame_a.get_column(2);
+                    let limit_lambda = -effective_mass * (self.bias_factor / dt) * limit_error * self.relaxation_factor;
+
+                    if (*self.body_a).inv_mass > 0.0 {
+                        (*self.body_a).angular_velocity -= axis * (limit_lambda * inv_inertia_a);
+                    }
+                    if (*self.body_b).inv_mass > 0.0 {
+                        (*self.body_b).angular_velocity += axis * (limit_lambda * inv_inertia_b);
+                    }
+                }
+            }
+
+            // Optional limit on the axis2 (y) swing
+            if let Some((min_angle, max_angle)) = self.limit2 {
+                let limit_error = if self.swing_angle2 < min_angle {
+                    self.swing_angle2 - min_angle
+                } else if self.swing_angle2 > max_angle {
+                    self.swing_angle2 - max_angle
+                } else {
+                    0.0
+                };
+
+                if limit_error != 0.0 {
+                    let axis = self.world_frame_a.get_column(1);
+                    let limit_lambda = -effective_mass * (self.bias_factor / dt) * limit_error * self.relaxation_factor;
+
+                    if (*self.body_a).inv_mass > 0.0 {
+                        (*self.body_a).angular_velocity -= axis * (limit_lambda * inv_inertia_a);
+                    }
+                    if (*self.body_b).inv_mass > 0.0 {
+                        (*self.body_b).angular_velocity += axis * (limit_lambda * inv_inertia_b);
+                    }
+                }
+            }
+        }
+    }
+
+    fn post_solve(&mut self) {
+        // Impulse is retained across frames for warm-starting, not cleared here
+    }
+
+    fn reset_accumulators(&mut self) {
+        self.point_accumulated_impulse = Vector3::zero();
+    }
+}
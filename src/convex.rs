@@ -0,0 +1,271 @@
+use crate::math_utils::Vector3;
+
+/// Arbitrary convex collision geometry, defined by its vertex set in body-local
+/// space. GJK/EPA only ever need a support function, so no face/edge topology is
+/// precomputed — the hull is implicit in its vertices.
+#[derive(Debug, Clone, Default)]
+pub struct ConvexHull {
+    pub vertices: Vec<Vector3>,
+}
+
+impl ConvexHull {
+    pub fn new(vertices: Vec<Vector3>) -> Self {
+        Self { vertices }
+    }
+
+    /// The support point of this hull (translated by `center`) farthest along
+    /// `direction`: `support(d) = argmax(v . d)`
+    fn support(&self, center: Vector3, direction: Vector3) -> Vector3 {
+        self.vertices.iter()
+            .copied()
+            .max_by(|a, b| a.dot(&direction).partial_cmp(&b.dot(&direction)).unwrap())
+            .map(|v| center + v)
+            .unwrap_or(center)
+    }
+}
+
+/// A support point of the Minkowski difference `A - B`. Only the difference
+/// point is kept; the hull points that produced it are not stored.
+#[derive(Debug, Clone, Copy)]
+struct SupportPoint {
+    point: Vector3,
+}
+
+fn minkowski_support(a: &ConvexHull, center_a: Vector3, b: &ConvexHull, center_b: Vector3, direction: Vector3) -> SupportPoint {
+    let on_a = a.support(center_a, direction);
+    let on_b = b.support(center_b, direction * -1.0);
+    SupportPoint { point: on_a - on_b }
+}
+
+/// Penetration depth and world-space normal (pointing from A to B) for two
+/// overlapping convex hulls
+#[derive(Debug, Clone, Copy)]
+pub struct ConvexContact {
+    pub normal: Vector3,
+    pub penetration: f32,
+}
+
+const GJK_MAX_ITERATIONS: usize = 32;
+const EPA_MAX_ITERATIONS: usize = 32;
+const EPA_TOLERANCE: f32 = 1e-4;
+
+/// Tests two convex hulls (translated by `center_a`/`center_b`) for overlap using
+/// GJK, then runs EPA on the resulting simplex to recover the penetration depth
+/// and normal if they do overlap. Returns `None` if the hulls are separated.
+///
+/// GJK iteratively builds a simplex of Minkowski-difference support points,
+/// pushing the search direction toward the origin each iteration, and terminates
+/// when the simplex encloses the origin (intersection) or a new support point
+/// makes no further progress toward it (separated).
+pub fn gjk_epa(a: &ConvexHull, center_a: Vector3, b: &ConvexHull, center_b: Vector3) -> Option<ConvexContact> {
+    if a.vertices.is_empty() || b.vertices.is_empty() {
+        return None;
+    }
+
+    let mut direction = center_b - center_a;
+    if direction.dot(&direction) < 1e-12 {
+        direction = Vector3::new(1.0, 0.0, 0.0);
+    }
+
+    let mut simplex = vec![minkowski_support(a, center_a, b, center_b, direction)];
+    direction = simplex[0].point * -1.0;
+
+    for _ in 0..GJK_MAX_ITERATIONS {
+        let candidate = minkowski_support(a, center_a, b, center_b, direction);
+        if candidate.point.dot(&direction) < 0.0 {
+            return None; // new support made no progress toward the origin: separated
+        }
+        simplex.push(candidate);
+
+        if do_simplex(&mut simplex, &mut direction) {
+            return epa(a, center_a, b, center_b, simplex);
+        }
+    }
+
+    None
+}
+
+fn same_direction(a: Vector3, b: Vector3) -> bool {
+    a.dot(&b) > 0.0
+}
+
+/// Reduces `simplex` toward the smallest feature closest to the origin and
+/// updates `direction` to search from there. Returns `true` once `simplex` is a
+/// tetrahedron enclosing the origin (GJK terminates: the hulls overlap).
+fn do_simplex(simplex: &mut Vec<SupportPoint>, direction: &mut Vector3) -> bool {
+    match simplex.len() {
+        2 => {
+            line_case(simplex, direction);
+            false
+        }
+        3 => {
+            triangle_case(simplex, direction);
+            false
+        }
+        4 => tetrahedron_case(simplex, direction),
+        _ => unreachable!("GJK simplex should never exceed 4 points"),
+    }
+}
+
+fn line_case(simplex: &mut Vec<SupportPoint>, direction: &mut Vector3) {
+    let a = simplex[1].point;
+    let b = simplex[0].point;
+    let ab = b - a;
+    let ao = a * -1.0;
+
+    if same_direction(ab, ao) {
+        *direction = ab.cross(&ao).cross(&ab);
+    } else {
+        *simplex = vec![simplex[1]];
+        *direction = ao;
+    }
+}
+
+fn triangle_case(simplex: &mut Vec<SupportPoint>, direction: &mut Vector3) {
+    let a = simplex[2].point;
+    let b = simplex[1].point;
+    let c = simplex[0].point;
+    let ab = b - a;
+    let ac = c - a;
+    let ao = a * -1.0;
+    let abc = ab.cross(&ac);
+
+    if same_direction(abc.cross(&ac), ao) {
+        if same_direction(ac, ao) {
+            *simplex = vec![simplex[0], simplex[2]];
+            line_case(simplex, direction);
+        } else {
+            *simplex = vec![simplex[1], simplex[2]];
+            line_case(simplex, direction);
+        }
+    } else if same_direction(ab.cross(&abc), ao) {
+        *simplex = vec![simplex[1], simplex[2]];
+        line_case(simplex, direction);
+    } else if same_direction(abc, ao) {
+        *direction = abc;
+    } else {
+        *simplex = vec![simplex[1], simplex[0], simplex[2]];
+        *direction = abc * -1.0;
+    }
+}
+
+fn tetrahedron_case(simplex: &mut Vec<SupportPoint>, direction: &mut Vector3) -> bool {
+    let a = simplex[3].point;
+    let b = simplex[2].point;
+    let c = simplex[1].point;
+    let d = simplex[0].point;
+    let ab = b - a;
+    let ac = c - a;
+    let ad = d - a;
+    let ao = a * -1.0;
+
+    let abc = ab.cross(&ac);
+    let acd = ac.cross(&ad);
+    let adb = ad.cross(&ab);
+
+    if same_direction(abc, ao) {
+        *simplex = vec![simplex[1], simplex[2], simplex[3]];
+        triangle_case(simplex, direction);
+        return false;
+    }
+    if same_direction(acd, ao) {
+        *simplex = vec![simplex[0], simplex[1], simplex[3]];
+        triangle_case(simplex, direction);
+        return false;
+    }
+    if same_direction(adb, ao) {
+        *simplex = vec![simplex[0], simplex[2], simplex[3]];
+        triangle_case(simplex, direction);
+        return false;
+    }
+
+    true // origin lies inside the tetrahedron: the hulls overlap
+}
+
+fn face_normal(polytope: &[SupportPoint], face: [usize; 3]) -> Vector3 {
+    let a = polytope[face[0]].point;
+    let b = polytope[face[1]].point;
+    let c = polytope[face[2]].point;
+    let mut normal = (b - a).cross(&(c - a)).normalize();
+    if normal.dot(&a) < 0.0 {
+        normal = normal * -1.0;
+    }
+    normal
+}
+
+fn add_edge(edges: &mut Vec<(usize, usize)>, a: usize, b: usize) {
+    if let Some(pos) = edges.iter().position(|&(x, y)| x == b && y == a) {
+        edges.remove(pos);
+    } else {
+        edges.push((a, b));
+    }
+}
+
+/// Expands a polytope of the Minkowski difference, starting from the terminal GJK
+/// tetrahedron, by repeatedly adding the support point in the direction of the
+/// closest face until that face's distance from the origin stops improving. The
+/// final closest face's normal and distance are the penetration normal and depth.
+fn epa(a: &ConvexHull, center_a: Vector3, b: &ConvexHull, center_b: Vector3, simplex: Vec<SupportPoint>) -> Option<ConvexContact> {
+    let mut polytope = simplex;
+    let mut faces: Vec<[usize; 3]> = vec![[0, 1, 2], [0, 3, 1], [0, 2, 3], [1, 3, 2]];
+
+    for _ in 0..EPA_MAX_ITERATIONS {
+        let mut min_distance = f32::MAX;
+        let mut min_face = 0;
+        let mut min_normal = Vector3::zero();
+
+        for (i, &face) in faces.iter().enumerate() {
+            let normal = face_normal(&polytope, face);
+            let distance = normal.dot(&polytope[face[0]].point);
+            if distance < min_distance {
+                min_distance = distance;
+                min_face = i;
+                min_normal = normal;
+            }
+        }
+        let _ = min_face;
+
+        let support = minkowski_support(a, center_a, b, center_b, min_normal);
+        let support_distance = support.point.dot(&min_normal);
+
+        if support_distance - min_distance < EPA_TOLERANCE {
+            return Some(ConvexContact { normal: min_normal, penetration: min_distance });
+        }
+
+        let new_index = polytope.len();
+        polytope.push(support);
+
+        let mut unique_edges = Vec::new();
+        let mut i = 0;
+        while i < faces.len() {
+            let face = faces[i];
+            let normal = face_normal(&polytope, face);
+            if normal.dot(&(support.point - polytope[face[0]].point)) > 0.0 {
+                add_edge(&mut unique_edges, face[0], face[1]);
+                add_edge(&mut unique_edges, face[1], face[2]);
+                add_edge(&mut unique_edges, face[2], face[0]);
+                faces.remove(i);
+            } else {
+                i += 1;
+            }
+        }
+
+        for (e0, e1) in unique_edges {
+            faces.push([e0, e1, new_index]);
+        }
+    }
+
+    // Ran out of iterations; report the best face found so far rather than
+    // silently failing the query.
+    let mut min_distance = f32::MAX;
+    let mut min_normal = Vector3::zero();
+    for &face in &faces {
+        let normal = face_normal(&polytope, face);
+        let distance = normal.dot(&polytope[face[0]].point);
+        if distance < min_distance {
+            min_distance = distance;
+            min_normal = normal;
+        }
+    }
+    Some(ConvexContact { normal: min_normal, penetration: min_distance })
+}
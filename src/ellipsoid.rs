@@ -0,0 +1,114 @@
+use crate::math_utils::{Vector3, Matrix3};
+use crate::collision::AABB;
+
+/// An oriented ellipsoid shape: the sphere of radius 1 scaled by `radii` along
+/// its local axes, then rotated and placed at `position`
+#[derive(Debug, Clone, Copy)]
+pub struct Ellipsoid {
+    pub position: Vector3,
+    pub rotation: Matrix3,
+    pub radii: Vector3,
+}
+
+impl Ellipsoid {
+    pub fn new(position: Vector3, rotation: Matrix3, radii: Vector3) -> Self {
+        Self { position, rotation, radii }
+    }
+}
+
+fn add_matrix3(a: Matrix3, b: Matrix3) -> Matrix3 {
+    let mut result = Matrix3::new();
+    for i in 0..3 {
+        for j in 0..3 {
+            result.m[i][j] = a.m[i][j] + b.m[i][j];
+        }
+    }
+    result
+}
+
+/// Builds this ellipsoid's shape matrix `S = R · diag(radii²) · Rᵀ`; the
+/// ellipsoid's surface is the set of offsets `x` with `xᵀ · S⁻¹ · x = 1`
+fn shape_matrix(ellipsoid: &Ellipsoid) -> Matrix3 {
+    let diag = Matrix3::from_rows(
+        Vector3::new(ellipsoid.radii.x * ellipsoid.radii.x, 0.0, 0.0),
+        Vector3::new(0.0, ellipsoid.radii.y * ellipsoid.radii.y, 0.0),
+        Vector3::new(0.0, 0.0, ellipsoid.radii.z * ellipsoid.radii.z),
+    );
+    ellipsoid.rotation * diag * ellipsoid.rotation.transpose()
+}
+
+/// Computes `r̂ᵀ · M⁻¹ · r̂` for a symmetric 3x3 matrix without forming the
+/// inverse explicitly: solves `M x = r̂` via Cramer's rule and returns `r̂ · x`.
+/// Kept local rather than a general `Matrix3::inverse`, since this quadratic
+/// form is the only thing the Gay-Berne contact distance below needs.
+fn quadratic_form_inverse(m: &Matrix3, r_hat: Vector3) -> f32 {
+    let a = m.m;
+    let det = a[0][0] * (a[1][1] * a[2][2] - a[1][2] * a[2][1])
+        - a[0][1] * (a[1][0] * a[2][2] - a[1][2] * a[2][0])
+        + a[0][2] * (a[1][0] * a[2][1] - a[1][1] * a[2][0]);
+
+    if det.abs() < 1e-12 {
+        return 0.0;
+    }
+
+    let solve_column = |col: usize| -> f32 {
+        let mut replaced = a;
+        replaced[0][col] = r_hat.x;
+        replaced[1][col] = r_hat.y;
+        replaced[2][col] = r_hat.z;
+        let numerator = replaced[0][0] * (replaced[1][1] * replaced[2][2] - replaced[1][2] * replaced[2][1])
+            - replaced[0][1] * (replaced[1][0] * replaced[2][2] - replaced[1][2] * replaced[2][0])
+            + replaced[0][2] * (replaced[1][0] * replaced[2][1] - replaced[1][1] * replaced[2][0]);
+        numerator / det
+    };
+
+    let x = Vector3::new(solve_column(0), solve_column(1), solve_column(2));
+    r_hat.dot(&x)
+}
+
+/// Tests two ellipsoids for overlap using the Gay-Berne-style anisotropic
+/// contact distance: the center-to-center direction `r̂` is tested against the
+/// combined shape matrix `S_a + S_b`, giving an effective contact distance
+/// `sigma(r̂) = (½ r̂ᵀ (S_a + S_b)⁻¹ r̂)^(-1/2)` that depends on how the two
+/// ellipsoids are oriented relative to each other, not just their radii.
+/// Returns the penetration depth and the separating normal (pointing from `a`
+/// to `b`) when the center distance is below `sigma`.
+pub fn compute_ellipsoid_collision(a: &Ellipsoid, b: &Ellipsoid) -> Option<(f32, Vector3)> {
+    let delta = b.position - a.position;
+    let distance = delta.length();
+    if distance < 1e-6 {
+        return None;
+    }
+    let r_hat = delta * (1.0 / distance);
+
+    let combined_shape = add_matrix3(shape_matrix(a), shape_matrix(b));
+    let half_quadratic_form = 0.5 * quadratic_form_inverse(&combined_shape, r_hat);
+    if half_quadratic_form <= 0.0 {
+        return None;
+    }
+    let sigma = 1.0 / half_quadratic_form.sqrt();
+
+    if distance < sigma {
+        Some((sigma - distance, r_hat))
+    } else {
+        None
+    }
+}
+
+/// The world-space AABB bounding a rotated ellipsoid: for each world axis `i`,
+/// the half-extent is `sqrt(sum_j (R[i][j] * radii[j])²)`, so ellipsoids can
+/// feed the existing broad-phase alongside spheres and boxes.
+pub fn ellipsoid_aabb(ellipsoid: &Ellipsoid) -> AABB {
+    let r = &ellipsoid.rotation.m;
+    let radii = ellipsoid.radii;
+    let extent = Vector3::new(
+        ((r[0][0] * radii.x).powi(2) + (r[0][1] * radii.y).powi(2) + (r[0][2] * radii.z).powi(2)).sqrt(),
+        ((r[1][0] * radii.x).powi(2) + (r[1][1] * radii.y).powi(2) + (r[1][2] * radii.z).powi(2)).sqrt(),
+        ((r[2][0] * radii.x).powi(2) + (r[2][1] * radii.y).powi(2) + (r[2][2] * radii.z).powi(2)).sqrt(),
+    );
+
+    AABB {
+        min: ellipsoid.position - extent,
+        max: ellipsoid.position + extent,
+    }
+}
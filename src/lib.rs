@@ -2,13 +2,21 @@
 
 pub mod math_utils;
 pub mod aabb;
-pub mod rigid_body;
 pub mod broad_phase;
 pub mod world;
 pub mod constraints;
 pub mod collision;
+pub mod mesh;
+pub mod scene;
+pub mod convex;
+pub mod ellipsoid;
+pub mod steering;
+pub mod timer;
+pub mod physics;
+#[cfg(feature = "parallel")]
+pub mod solver;
 
 
 pub use world::PhysicsWorld;
-pub use rigid_body::RigidBody;
+pub use aabb::RigidBody;
 pub use math_utils::Vector3;
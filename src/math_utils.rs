@@ -22,6 +22,19 @@ impl Vector3 {
         (self.x * self.x + self.y * self.y + self.z * self.z).sqrt()
     }
 
+    /// The squared length, avoiding the `sqrt` for comparisons like radius checks
+    pub fn length_squared(&self) -> f32 {
+        self.x * self.x + self.y * self.y + self.z * self.z
+    }
+
+    pub fn distance(&self, other: &Self) -> f32 {
+        (*self - *other).length()
+    }
+
+    pub fn distance_squared(&self, other: &Self) -> f32 {
+        (*self - *other).length_squared()
+    }
+
     pub fn normalize(&self) -> Self {
         let len = self.length();
         if len > 0.0 {
@@ -46,6 +59,26 @@ impl Vector3 {
             z: self.x * other.y - self.y * other.x,
         }
     }
+
+    /// The component of `self` parallel to `onto`
+    pub fn project_on(&self, onto: &Self) -> Self {
+        *onto * (self.dot(onto) / onto.length_squared())
+    }
+
+    /// The component of `self` perpendicular to `onto`
+    pub fn reject_from(&self, onto: &Self) -> Self {
+        *self - self.project_on(onto)
+    }
+
+    /// The angle in radians between `self` and `other`, in `[0, PI]`
+    pub fn angle_between(&self, other: &Self) -> f32 {
+        let denom = self.length() * other.length();
+        if denom > 0.0 {
+            (self.dot(other) / denom).clamp(-1.0, 1.0).acos()
+        } else {
+            0.0
+        }
+    }
 }
 
 impl Add for Vector3 {
@@ -182,6 +215,135 @@ impl Matrix3 {
             ],
         }
     }
+
+    pub fn identity() -> Self {
+        Self::from_diagonal(1.0)
+    }
+
+    /// The skew-symmetric cross-product matrix of `v`, such that
+    /// `skew_symmetric(v) * w == v.cross(&w)` for any `w`
+    pub fn skew_symmetric(v: Vector3) -> Self {
+        Self {
+            m: [
+                [0.0, -v.z, v.y],
+                [v.z, 0.0, -v.x],
+                [-v.y, v.x, 0.0],
+            ],
+        }
+    }
+
+    pub fn determinant(&self) -> f32 {
+        let m = self.m;
+        m[0][0] * (m[1][1] * m[2][2] - m[1][2] * m[2][1])
+            - m[0][1] * (m[1][0] * m[2][2] - m[1][2] * m[2][0])
+            + m[0][2] * (m[1][0] * m[2][1] - m[1][1] * m[2][0])
+    }
+
+    /// Diagonalizes this symmetric matrix via the cyclic Jacobi eigenvalue
+    /// method: repeatedly zeroes the largest off-diagonal element with a Givens
+    /// rotation until the largest off-diagonal entry falls below `1e-6` (or 50
+    /// sweeps elapse). Returns `(principal_axes, moments)`, where each column of
+    /// `principal_axes` is an eigenvector and `moments` holds the matching
+    /// eigenvalues — for an inertia tensor, its principal axes and moments.
+    pub fn diagonalize(&self) -> (Self, Vector3) {
+        let mut a = *self;
+        let mut v = Self::identity();
+
+        for _ in 0..50 {
+            // Find the largest off-diagonal element (i, j), i != j
+            let mut i = 0;
+            let mut j = 1;
+            let mut max_val = a.m[0][1].abs();
+            for (ii, jj) in [(0, 2), (1, 2)] {
+                if a.m[ii][jj].abs() > max_val {
+                    max_val = a.m[ii][jj].abs();
+                    i = ii;
+                    j = jj;
+                }
+            }
+
+            if max_val < 1e-6 {
+                break;
+            }
+
+            let theta = if (a.m[i][i] - a.m[j][j]).abs() < 1e-9 {
+                PI / 4.0
+            } else {
+                0.5 * (2.0 * a.m[i][j] / (a.m[j][j] - a.m[i][i])).atan()
+            };
+            let (s, c) = theta.sin_cos();
+
+            let mut rotation = Self::identity();
+            rotation.m[i][i] = c;
+            rotation.m[j][j] = c;
+            rotation.m[i][j] = s;
+            rotation.m[j][i] = -s;
+
+            a = rotation.transpose() * a * rotation;
+            v = v * rotation;
+        }
+
+        (v, Vector3::new(a.m[0][0], a.m[1][1], a.m[2][2]))
+    }
+
+    /// Inverts the matrix via the adjugate-over-determinant formula. Returns
+    /// `None` if the matrix is singular (determinant near zero).
+    pub fn inverse(&self) -> Option<Self> {
+        let m = self.m;
+        let det = self.determinant();
+        if det.abs() < 1e-9 {
+            return None;
+        }
+        let inv_det = 1.0 / det;
+
+        Some(Self {
+            m: [
+                [
+                    (m[1][1] * m[2][2] - m[1][2] * m[2][1]) * inv_det,
+                    (m[0][2] * m[2][1] - m[0][1] * m[2][2]) * inv_det,
+                    (m[0][1] * m[1][2] - m[0][2] * m[1][1]) * inv_det,
+                ],
+                [
+                    (m[1][2] * m[2][0] - m[1][0] * m[2][2]) * inv_det,
+                    (m[0][0] * m[2][2] - m[0][2] * m[2][0]) * inv_det,
+                    (m[0][2] * m[1][0] - m[0][0] * m[1][2]) * inv_det,
+                ],
+                [
+                    (m[1][0] * m[2][1] - m[1][1] * m[2][0]) * inv_det,
+                    (m[0][1] * m[2][0] - m[0][0] * m[2][1]) * inv_det,
+                    (m[0][0] * m[1][1] - m[0][1] * m[1][0]) * inv_det,
+                ],
+            ],
+        })
+    }
+}
+
+impl Add for Matrix3 {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        let mut result = Self::new();
+        for i in 0..3 {
+            for j in 0..3 {
+                result.m[i][j] = self.m[i][j] + other.m[i][j];
+            }
+        }
+        result
+    }
+}
+
+impl Sub for Matrix3 {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        let mut result = Self::new();
+        for i in 0..3 {
+            for j in 0..3 {
+                result.m[i][j] = self.m[i][j] - other.m[i][j];
+            }
+        }
+        result
+    }
 }
 
 impl Mul<Vector3> for Matrix3 {
@@ -244,6 +406,65 @@ impl Quaternion {
         }
     }
 
+    /// Builds the quaternion representing a rotation of `angle_rad` radians about `axis`
+    pub fn from_axis_angle(axis: Vector3, angle_rad: f32) -> Self {
+        let half_angle = angle_rad * 0.5;
+        let axis = axis.normalize();
+        let s = half_angle.sin();
+        Self {
+            w: half_angle.cos(),
+            x: axis.x * s,
+            y: axis.y * s,
+            z: axis.z * s,
+        }
+    }
+
+    /// Builds a rotation from pitch (X), yaw (Y), and roll (Z) angles in radians, composed
+    /// as `yaw * pitch * roll` (Y then X then Z, applied right-to-left)
+    pub fn from_euler(pitch: f32, yaw: f32, roll: f32) -> Self {
+        let pitch_q = Self::from_axis_angle(Vector3::new(1.0, 0.0, 0.0), pitch);
+        let yaw_q = Self::from_axis_angle(Vector3::new(0.0, 1.0, 0.0), yaw);
+        let roll_q = Self::from_axis_angle(Vector3::new(0.0, 0.0, 1.0), roll);
+        yaw_q * pitch_q * roll_q
+    }
+
+    /// Spherically interpolates between `self` and `other` by `t` in `[0, 1]`, taking the
+    /// short arc and falling back to a normalized linear interpolation when the two
+    /// quaternions are nearly parallel (where the slerp formula becomes numerically unstable)
+    pub fn slerp(&self, other: &Self, t: f32) -> Self {
+        let mut dot = self.w * other.w + self.x * other.x + self.y * other.y + self.z * other.z;
+
+        let other = if dot < 0.0 {
+            dot = -dot;
+            Self::new(-other.w, -other.x, -other.y, -other.z)
+        } else {
+            *other
+        };
+
+        if dot > 0.9995 {
+            let mut result = Self::new(
+                self.w + (other.w - self.w) * t,
+                self.x + (other.x - self.x) * t,
+                self.y + (other.y - self.y) * t,
+                self.z + (other.z - self.z) * t,
+            );
+            result.normalize();
+            return result;
+        }
+
+        let theta = dot.acos();
+        let sin_theta = theta.sin();
+        let a = ((1.0 - t) * theta).sin() / sin_theta;
+        let b = (t * theta).sin() / sin_theta;
+
+        Self::new(
+            self.w * a + other.w * b,
+            self.x * a + other.x * b,
+            self.y * a + other.y * b,
+            self.z * a + other.z * b,
+        )
+    }
+
     pub fn conjugate(&self) -> Self {
         Self {
             w: self.w,
@@ -396,6 +617,12 @@ pub mod math_utils {
         )
     }
 
+    /// Transforms a body-space inertia tensor to world space as `R * I_body * Rᵀ`, given
+    /// the body's current orientation matrix `R`
+    pub fn calculate_world_inertia_tensor(inertia_body: Matrix3, rotation: Matrix3) -> Matrix3 {
+        rotation * inertia_body * rotation.transpose()
+    }
+
     pub fn calculate_angular_velocity(linear_velocity: f32, radius: f32) -> f32 {
         linear_velocity / radius
     }
@@ -422,7 +649,7 @@ pub mod math_utils {
     }
 
     pub fn calculate_friction_impulse(normal: Vector3, friction: f32, relative_velocity: Vector3, inv_mass_a: f32, inv_mass_b: f32) -> Vector3 {
-        let tangent = relative_velocity - normal * relative_velocity.dot(&normal);
+        let tangent = relative_velocity.reject_from(&normal);
         let tangent_length = tangent.length();
         if tangent_length > 0.0 {
             let tangent = tangent / tangent_length;
@@ -432,4 +659,107 @@ pub mod math_utils {
             Vector3::zero()
         }
     }
+}
+
+// Property-based tests over the algebraic invariants of Vector3, Quaternion,
+// and Matrix3. Requires `proptest` as a dev-dependency:
+//   [dev-dependencies]
+//   proptest = "1"
+#[cfg(test)]
+mod proptests {
+    use super::*;
+    use proptest::prelude::*;
+
+    // Bounded to keep f32 products/sums well away from overflow or
+    // catastrophic cancellation.
+    const COMPONENT_RANGE: std::ops::Range<f32> = -100.0..100.0;
+
+    fn vector3_strategy() -> impl Strategy<Value = Vector3> {
+        (COMPONENT_RANGE, COMPONENT_RANGE, COMPONENT_RANGE)
+            .prop_map(|(x, y, z)| Vector3::new(x, y, z))
+    }
+
+    fn nonzero_vector3_strategy() -> impl Strategy<Value = Vector3> {
+        vector3_strategy().prop_filter("vector must be non-zero", |v| v.length() > 1e-3)
+    }
+
+    fn quaternion_length(q: &Quaternion) -> f32 {
+        (q.w * q.w + q.x * q.x + q.y * q.y + q.z * q.z).sqrt()
+    }
+
+    fn quaternion_strategy() -> impl Strategy<Value = Quaternion> {
+        (COMPONENT_RANGE, COMPONENT_RANGE, COMPONENT_RANGE, COMPONENT_RANGE)
+            .prop_map(|(w, x, y, z)| Quaternion::new(w, x, y, z))
+            .prop_filter("quaternion must be non-zero", |q| quaternion_length(q) > 1e-3)
+    }
+
+    fn matrix3_strategy() -> impl Strategy<Value = Matrix3> {
+        prop::collection::vec(-10.0f32..10.0, 9).prop_map(|c| Matrix3 {
+            m: [
+                [c[0], c[1], c[2]],
+                [c[3], c[4], c[5]],
+                [c[6], c[7], c[8]],
+            ],
+        })
+    }
+
+    fn approx_eq(a: f32, b: f32, magnitude: f32) -> bool {
+        let epsilon = 1e-3 * magnitude.max(1.0);
+        (a - b).abs() <= epsilon
+    }
+
+    fn vectors_approx_eq(a: Vector3, b: Vector3) -> bool {
+        let magnitude = a.length().max(b.length());
+        approx_eq(a.x, b.x, magnitude) && approx_eq(a.y, b.y, magnitude) && approx_eq(a.z, b.z, magnitude)
+    }
+
+    fn matrices_approx_eq(a: Matrix3, b: Matrix3) -> bool {
+        for row in 0..3 {
+            for col in 0..3 {
+                if !approx_eq(a.m[row][col], b.m[row][col], 10.0) {
+                    return false;
+                }
+            }
+        }
+        true
+    }
+
+    proptest! {
+        #[test]
+        fn normalize_has_unit_length(v in nonzero_vector3_strategy()) {
+            prop_assert!(approx_eq(v.normalize().length(), 1.0, 1.0));
+        }
+
+        #[test]
+        fn cross_is_perpendicular_to_both_operands(a in nonzero_vector3_strategy(), b in nonzero_vector3_strategy()) {
+            let c = a.cross(&b);
+            let magnitude = a.length() * b.length() * c.length();
+            prop_assert!(approx_eq(c.dot(&a), 0.0, magnitude.max(1.0)));
+            prop_assert!(approx_eq(c.dot(&b), 0.0, magnitude.max(1.0)));
+        }
+
+        #[test]
+        fn quaternion_product_matches_matrix_product(q1 in quaternion_strategy(), q2 in quaternion_strategy()) {
+            let lhs = (q1 * q2).to_matrix();
+            let rhs = q1.to_matrix() * q2.to_matrix();
+            prop_assert!(matrices_approx_eq(lhs, rhs));
+        }
+
+        #[test]
+        fn normalized_quaternion_yields_orthonormal_matrix(q in quaternion_strategy()) {
+            let mut q = q;
+            q.normalize();
+            let m = q.to_matrix();
+            prop_assert!(matrices_approx_eq(m * m.transpose(), Matrix3::identity()));
+        }
+
+        #[test]
+        fn inverse_of_well_conditioned_matrix_is_identity(m in matrix3_strategy()) {
+            // Keep the determinant well away from zero so f32 rounding stays within tolerance
+            prop_assume!(m.determinant().abs() > 1.0);
+            if let Some(inv) = m.inverse() {
+                prop_assert!(matrices_approx_eq(inv * m, Matrix3::identity()));
+            }
+        }
+    }
 } 
\ No newline at end of file
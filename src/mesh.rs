@@ -0,0 +1,143 @@
+use crate::math_utils::Vector3;
+
+/// A single static collision triangle, defined by its three world-space vertices
+#[derive(Debug, Clone, Copy)]
+pub struct Triangle {
+    pub v0: Vector3,
+    pub v1: Vector3,
+    pub v2: Vector3,
+}
+
+impl Triangle {
+    pub fn new(v0: Vector3, v1: Vector3, v2: Vector3) -> Self {
+        Self { v0, v1, v2 }
+    }
+
+    /// Computes the triangle's (non-normalized direction preserved) unit plane normal
+    pub fn normal(&self) -> Vector3 {
+        (self.v1 - self.v0).cross(&(self.v2 - self.v0)).normalize()
+    }
+
+    /// Checks whether a point known to lie on the triangle's plane is inside its edges
+    fn contains_point(&self, point: Vector3, normal: Vector3) -> bool {
+        let edge0 = self.v1 - self.v0;
+        let edge1 = self.v2 - self.v1;
+        let edge2 = self.v0 - self.v2;
+
+        normal.cross(&edge0).dot(&(point - self.v0)) >= 0.0
+            && normal.cross(&edge1).dot(&(point - self.v1)) >= 0.0
+            && normal.cross(&edge2).dot(&(point - self.v2)) >= 0.0
+    }
+}
+
+/// Static collision geometry made of a list of triangles, used by
+/// `CollisionShape::TriangleMesh` bodies (level geometry, terrain, etc.)
+#[derive(Debug, Clone, Default)]
+pub struct TriangleMesh {
+    pub triangles: Vec<Triangle>,
+}
+
+impl TriangleMesh {
+    pub fn new(triangles: Vec<Triangle>) -> Self {
+        Self { triangles }
+    }
+
+    /// Adds a triangle to the mesh
+    pub fn add_triangle(&mut self, triangle: Triangle) {
+        self.triangles.push(triangle);
+    }
+}
+
+/// The result of a sphere-vs-mesh collision query
+#[derive(Debug, Clone, Copy)]
+pub struct MeshContact {
+    pub normal: Vector3,
+    pub penetration: f32,
+    pub triangle_index: usize,
+}
+
+/// Tests a static sphere against a triangle mesh, returning the deepest contact if any
+pub fn sphere_vs_mesh(center: Vector3, radius: f32, mesh: &TriangleMesh) -> Option<MeshContact> {
+    let mut best: Option<MeshContact> = None;
+
+    for (index, triangle) in mesh.triangles.iter().enumerate() {
+        let normal = triangle.normal();
+        let signed_distance = normal.dot(&(center - triangle.v0));
+        if signed_distance.abs() > radius {
+            continue;
+        }
+
+        let projected = center - normal * signed_distance;
+        if !triangle.contains_point(projected, normal) {
+            continue;
+        }
+
+        let penetration = radius - signed_distance;
+        if penetration <= 0.0 {
+            continue;
+        }
+
+        let is_better = best.map_or(true, |b| penetration > b.penetration);
+        if is_better {
+            best = Some(MeshContact { normal, penetration, triangle_index: index });
+        }
+    }
+
+    best
+}
+
+/// The result of a swept-sphere-vs-mesh query: the impact fraction, contact normal,
+/// impact point, and which triangle was struck
+#[derive(Debug, Clone, Copy)]
+pub struct MeshSweepHit {
+    pub fraction: f32,
+    pub normal: Vector3,
+    pub point: Vector3,
+    pub triangle_index: usize,
+}
+
+/// Sweeps a moving sphere of radius `r` from `p0` to `p1` against a triangle mesh and
+/// returns the earliest impact, if any.
+///
+/// For each triangle with plane normal `n`, computes the signed distances
+/// `d0 = n·(p0 - v0)` and `d1 = n·(p1 - v0)`. If the sphere's offset surface
+/// (`d = r`) is crossed during the sweep, the impact fraction is
+/// `t = (d0 - r) / (d0 - d1)`. The impact point is accepted only if its projection
+/// onto the triangle's plane lies within all three edges.
+pub fn swept_sphere_vs_mesh(p0: Vector3, p1: Vector3, radius: f32, mesh: &TriangleMesh) -> Option<MeshSweepHit> {
+    let mut best: Option<MeshSweepHit> = None;
+
+    for (index, triangle) in mesh.triangles.iter().enumerate() {
+        let normal = triangle.normal();
+        let d0 = normal.dot(&(p0 - triangle.v0));
+        let d1 = normal.dot(&(p1 - triangle.v0));
+
+        // The sphere must cross the offset plane at distance r from the triangle
+        if !(d0 > radius && d1 < radius) {
+            continue;
+        }
+
+        let denom = d0 - d1;
+        if denom.abs() < 1e-9 {
+            continue;
+        }
+
+        let t = (d0 - radius) / denom;
+        if !(0.0..=1.0).contains(&t) {
+            continue;
+        }
+
+        let impact_point = p0 + (p1 - p0) * t;
+        let projected = impact_point - normal * (normal.dot(&(impact_point - triangle.v0)));
+        if !triangle.contains_point(projected, normal) {
+            continue;
+        }
+
+        let is_earlier = best.map_or(true, |b| t < b.fraction);
+        if is_earlier {
+            best = Some(MeshSweepHit { fraction: t, normal, point: impact_point, triangle_index: index });
+        }
+    }
+
+    best
+}
@@ -1,8 +1,9 @@
 use crate::math_utils::Vector3;
-use crate::rigid_body::{RigidBody, CollisionShape};
+use crate::aabb::{RigidBody, CollisionShape, CollisionGroups};
 use crate::world::PhysicsWorld;
 use crate::timer::Timer;
-use crate::constraints::HingeConstraint;
+use crate::constraints::{HingeConstraint, HingeSolverState};
+use crate::math_utils::Quaternion;
 use std::collections::HashMap;
 use std::f32::consts::PI;
 
@@ -15,12 +16,20 @@ pub struct Settings {
     pub gravity: Vector3,
     pub default_restitution: f32,
     pub default_friction: f32,
-    
+    pub default_collision_groups: CollisionGroups, // Groups new bodies get unless overridden
+    pub broadphase_cell_size: f32, // Uniform-grid broadphase cell size (see PhysicsWorld::set_broadphase_cell_size)
+
+    // Continuous collision detection
+    pub enable_ccd: bool,             // Opt-in conservative-advancement CCD pass
+    pub ccd_velocity_threshold: f32,  // Bodies slower than this never run the CCD pass
+
     // Visualization settings
     pub show_debug_draw: bool,
     pub show_colliders: bool,
     pub show_contacts: bool,
     pub show_grid: bool,
+    pub show_broadphase_cells: bool, // If set, show_grid draws occupied broadphase cells instead of the cosmetic floor grid
+    pub tint_colliders_by_group: bool, // Tint draw_colliders output by membership bits
     pub camera_position: Vector3,
     pub camera_target: Vector3,
     pub camera_fov: f32,
@@ -37,10 +46,16 @@ impl Default for Settings {
             gravity: Vector3::new(0.0, -9.81, 0.0),
             default_restitution: 0.5,
             default_friction: 0.3,
+            default_collision_groups: CollisionGroups::all(),
+            broadphase_cell_size: 4.0,
+            enable_ccd: false,
+            ccd_velocity_threshold: 10.0,
             show_debug_draw: false,
             show_colliders: true,
             show_contacts: false,
             show_grid: true,
+            show_broadphase_cells: false,
+            tint_colliders_by_group: false,
             camera_position: Vector3::new(0.0, 10.0, 20.0),
             camera_target: Vector3::zero(),
             camera_fov: 60.0,
@@ -58,15 +73,98 @@ pub struct DebugDrawData {
     pub texts: Vec<(String, Vector3, Vector3)>,   // (text, position, color)
 }
 
+/// Tracks how many consecutive frames a body has been resolved against the same
+/// surface by the CCD pass, so a body resting against thin geometry it keeps
+/// nearly tunneling into gets snapped and zeroed along that surface instead of
+/// jittering every frame.
+#[derive(Debug, Clone, Copy)]
+struct TunnelRecord {
+    frames: u32,
+    dir: Vector3,
+}
+
+impl Default for TunnelRecord {
+    fn default() -> Self {
+        Self { frames: 0, dir: Vector3::zero() }
+    }
+}
+
+/// Per-axis weights and limits for a `SteeringAgent`'s boids rules
+#[derive(Debug, Clone, Copy)]
+pub struct SteeringParams {
+    pub neighbor_radius: f32,     // Other agents farther than this are ignored
+    pub separation_weight: f32,   // Steer away from nearby agents' average displacement
+    pub alignment_weight: f32,    // Steer toward neighbours' average velocity
+    pub cohesion_weight: f32,     // Steer toward neighbours' centroid
+    pub max_force: f32,           // Clamp on the combined steering force
+    pub max_speed: f32,           // Clamp on the resulting velocity
+}
+
+impl Default for SteeringParams {
+    fn default() -> Self {
+        Self {
+            neighbor_radius: 5.0,
+            separation_weight: 1.5,
+            alignment_weight: 1.0,
+            cohesion_weight: 1.0,
+            max_force: 10.0,
+            max_speed: 5.0,
+        }
+    }
+}
+
+/// Binds a managed body to the boids-style rules that should steer it: each
+/// fixed step, `PhysicsEngine::apply_steering_forces` gathers nearby agents via
+/// a uniform grid and applies a weighted separation/alignment/cohesion force
+struct SteeringAgent {
+    body_index: usize,
+    params: SteeringParams,
+}
+
+/// Deterministic per-body state captured by `PhysicsEngine::save_state`
+#[derive(Debug, Clone, Copy)]
+pub struct BodySnapshot {
+    pub position: Vector3,
+    pub rotation: Quaternion,
+    pub velocity: Vector3,
+    pub angular_velocity: Vector3,
+    pub mass: f32,
+    pub shape: CollisionShape,
+    pub half_extents: Vector3,
+    pub radius: f32,
+}
+
+/// A deterministic capture of engine state for rollback netcode (e.g. GGRS-style
+/// resimulation): restore one with `load_state` and replay forward with
+/// `step_fixed` to reproduce the same trajectory, since `managed_body_indices` and
+/// `managed_constraints` are plain, index-ordered `Vec`s rather than `HashMap`s.
+///
+/// Only `HingeConstraint` state is captured; other constraint types aren't
+/// managed by `PhysicsEngine` today (see `managed_constraints`).
+#[derive(Debug, Clone)]
+pub struct WorldSnapshot {
+    pub bodies: Vec<BodySnapshot>,
+    pub hinge_constraints: Vec<HingeSolverState>,
+}
+
 /// The main physics engine that manages the simulation
 pub struct PhysicsEngine {
     world: PhysicsWorld,
     settings: Settings,
     simulation_timer: Timer,
-    managed_bodies: Vec<Box<RigidBody>>,
+    // Indices into `world.bodies()` for every body created through this engine (in
+    // creation order), so `PhysicsEngine`'s own bookkeeping (interpolation, snapshots,
+    // CCD, steering) reads and writes the exact same bodies `world.step()` simulates,
+    // rather than a parallel copy that drifts from what's actually been resolved.
+    managed_body_indices: Vec<usize>,
     managed_constraints: Vec<Box<HingeConstraint>>,
+    steering_agents: Vec<SteeringAgent>,
     debug_draw_data: DebugDrawData,
     web_server_running: bool,
+    tunnel_records: HashMap<usize, TunnelRecord>,
+    toi_contacts: Vec<(Vector3, Vector3)>, // (point, normal), for draw_contacts
+    accumulator: f32, // Leftover simulation time not yet consumed by a fixed step
+    previous_transforms: Vec<(Vector3, Quaternion)>, // Pre-step transforms, for interpolated_transform
 }
 
 impl PhysicsEngine {
@@ -76,10 +174,15 @@ impl PhysicsEngine {
             world: PhysicsWorld::new(),
             settings: Settings::default(),
             simulation_timer: Timer::new(),
-            managed_bodies: Vec::new(),
+            managed_body_indices: Vec::new(),
             managed_constraints: Vec::new(),
+            steering_agents: Vec::new(),
             debug_draw_data: DebugDrawData::default(),
             web_server_running: false,
+            tunnel_records: HashMap::new(),
+            toi_contacts: Vec::new(),
+            accumulator: 0.0,
+            previous_transforms: Vec::new(),
         }
     }
 
@@ -89,24 +192,42 @@ impl PhysicsEngine {
             self.settings = s;
         }
         self.world.clear();
+        self.world.set_broadphase_cell_size(self.settings.broadphase_cell_size);
+        self.managed_body_indices.clear();
         self.simulation_timer.reset();
         self.clear_debug_draw_data();
+        self.accumulator = 0.0;
+        self.previous_transforms.clear();
     }
 
     /// Updates the physics simulation
+    ///
+    /// Decouples the physics rate from the render rate with a fixed-timestep
+    /// accumulator: `delta_time` is added to `accumulator` and consumed in exact
+    /// `fixed_time_step` chunks (capped by `max_sub_steps` per call, to bound the
+    /// work done after a stall rather than spiral into more and more steps). Any
+    /// time left in `accumulator` carries over to the next call rather than being
+    /// discarded, so frame rates that don't evenly divide the physics rate don't
+    /// lose time. Use `interpolated_transform` to render bodies at the fractional
+    /// point between their last two fixed steps.
     pub fn update(&mut self, delta_time: f32) {
         self.simulation_timer.update();
 
         // Clamp deltaTime to avoid spiral of death
         let dt = delta_time.min(self.settings.max_time_step);
+        self.accumulator += dt;
 
-        // Fixed timestep updates
-        let mut remaining_time = dt;
+        // Each world step advances the world's own delta time; keep it at fixed_time_step
+        self.world.set_fixed_delta_time(self.settings.fixed_time_step);
         let mut substeps = 0;
-        while remaining_time > 0.0 && substeps < self.settings.max_sub_steps {
-            let step_time = remaining_time.min(self.settings.fixed_time_step);
+        while self.accumulator >= self.settings.fixed_time_step && substeps < self.settings.max_sub_steps {
+            self.store_previous_transforms();
+            self.apply_steering_forces();
+            if self.settings.enable_ccd {
+                self.apply_continuous_collision(self.settings.fixed_time_step);
+            }
             self.world.step();
-            remaining_time -= step_time;
+            self.accumulator -= self.settings.fixed_time_step;
             substeps += 1;
         }
 
@@ -116,12 +237,350 @@ impl PhysicsEngine {
         }
     }
 
+    /// Records each managed body's transform just before a fixed step, so
+    /// `interpolated_transform` has a "previous" state to blend from. Grows to
+    /// match `managed_body_indices` as bodies are added.
+    fn store_previous_transforms(&mut self) {
+        if self.previous_transforms.len() != self.managed_body_indices.len() {
+            self.previous_transforms.resize(self.managed_body_indices.len(), (Vector3::zero(), Quaternion::identity()));
+        }
+        let bodies = self.world.bodies();
+        for (slot, &world_index) in self.previous_transforms.iter_mut().zip(self.managed_body_indices.iter()) {
+            let body = &bodies[world_index];
+            *slot = (body.position, body.rotation);
+        }
+    }
+
+    /// Blends a managed body's previous and current transform by
+    /// `alpha = accumulator / fixed_time_step`, the fraction of a fixed step that
+    /// hasn't been simulated yet. Intended for rendering at a rate decoupled from
+    /// `fixed_time_step`. Returns the body's current transform if it has no
+    /// recorded previous state yet (e.g. added after the last fixed step).
+    pub fn interpolated_transform(&self, index: usize) -> (Vector3, Quaternion) {
+        let world_index = match self.managed_body_indices.get(index) {
+            Some(&idx) => idx,
+            None => return (Vector3::zero(), Quaternion::identity()),
+        };
+        let body = &self.world.bodies()[world_index];
+        let (prev_position, prev_rotation) = self.previous_transforms.get(index)
+            .copied()
+            .unwrap_or((body.position, body.rotation));
+
+        let alpha = (self.accumulator / self.settings.fixed_time_step).clamp(0.0, 1.0);
+        let position = prev_position + (body.position - prev_position) * alpha;
+        let rotation = nlerp(prev_rotation, body.rotation, alpha);
+        (position, rotation)
+    }
+
+    /// Captures a deterministic snapshot of every managed body and hinge
+    /// constraint, for rollback netcode to restore with `load_state` and replay
+    /// forward with `step_fixed`
+    pub fn save_state(&self) -> WorldSnapshot {
+        let bodies = self.world.bodies();
+        WorldSnapshot {
+            bodies: self.managed_body_indices.iter().map(|&world_index| {
+                let body = &bodies[world_index];
+                BodySnapshot {
+                    position: body.position,
+                    rotation: body.rotation,
+                    velocity: body.velocity,
+                    angular_velocity: body.angular_velocity,
+                    mass: body.mass,
+                    shape: body.shape,
+                    half_extents: body.half_extents,
+                    radius: body.radius,
+                }
+            }).collect(),
+            hinge_constraints: self.managed_constraints.iter().map(|c| c.solver_state()).collect(),
+        }
+    }
+
+    /// Restores engine state captured by `save_state`. The snapshot must come from
+    /// an engine with the same managed bodies/constraints already created (same
+    /// count and order); this restores their state in place rather than
+    /// recreating them.
+    pub fn load_state(&mut self, snapshot: &WorldSnapshot) {
+        let bodies = self.world.bodies_mut();
+        for (&world_index, saved) in self.managed_body_indices.iter().zip(snapshot.bodies.iter()) {
+            let body = &mut bodies[world_index];
+            body.position = saved.position;
+            body.rotation = saved.rotation;
+            body.velocity = saved.velocity;
+            body.angular_velocity = saved.angular_velocity;
+            body.set_mass(saved.mass);
+            body.shape = saved.shape;
+            body.half_extents = saved.half_extents;
+            body.radius = saved.radius;
+        }
+        for (constraint, saved) in self.managed_constraints.iter_mut().zip(snapshot.hinge_constraints.iter()) {
+            constraint.restore_solver_state(*saved);
+        }
+        self.tunnel_records.clear();
+        self.toi_contacts.clear();
+        self.previous_transforms.clear();
+    }
+
+    /// Advances the simulation by exactly one `fixed_time_step`, with no
+    /// wall-clock coupling (no `simulation_timer` update, no variable-`delta_time`
+    /// accumulator). Rollback netcode should call this to resimulate frames from a
+    /// restored `WorldSnapshot`, since the same starting state always reproduces
+    /// the same trajectory.
+    pub fn step_fixed(&mut self) {
+        if self.settings.enable_ccd {
+            self.apply_continuous_collision(self.settings.fixed_time_step);
+        }
+        self.world.set_fixed_delta_time(self.settings.fixed_time_step);
+        self.world.step();
+    }
+
+    /// Maps a collision group's membership bitmask to a debug-draw tint, so
+    /// `draw_colliders` can make the active layer/mask filtering visible. Each of
+    /// the low 3 bits selects a primary color channel; bodies in multiple layers
+    /// blend toward white.
+    fn collision_group_color(membership: u32) -> Vector3 {
+        let r = if membership & 0b001 != 0 { 1.0 } else { 0.2 };
+        let g = if membership & 0b010 != 0 { 1.0 } else { 0.2 };
+        let b = if membership & 0b100 != 0 { 1.0 } else { 0.2 };
+        Vector3::new(r, g, b)
+    }
+
+    /// Returns the radius of the largest sphere that fits inside this body's shape
+    /// (the smallest half-extent for boxes),
+    /// used as a conservative stand-in for "closest distance to a surface" since the
+    /// managed body list has no per-shape closest-point query yet.
+    fn smallest_extent(body: &RigidBody) -> f32 {
+        match body.shape {
+            CollisionShape::Sphere => body.radius,
+            _ => body.half_extents.x.min(body.half_extents.y).min(body.half_extents.z),
+        }
+    }
+
+    /// Conservative-advancement CCD pass, run before `world.step()` when
+    /// `Settings.enable_ccd` is set. For each dynamic body moving fast enough
+    /// (above `ccd_velocity_threshold`, and with a swept displacement this step
+    /// exceeding half its smallest extent) to plausibly tunnel through thin
+    /// geometry, repeatedly narrows the time of impact against every other body
+    /// (each treated as a sphere of radius `smallest_extent`) until the closest approach drops below a
+    /// small contact margin, then snaps the body to that time of impact.
+    ///
+    /// A body resolved against the same surface normal for two or more consecutive
+    /// frames has its velocity zeroed along that normal, so it comes to rest
+    /// against the surface instead of jittering as CCD re-triggers every frame.
+    fn apply_continuous_collision(&mut self, step_time: f32) {
+        const CONTACT_MARGIN: f32 = 0.01;
+        let mut contacts = Vec::new();
+        let mut resolved: Vec<usize> = Vec::new();
+
+        let managed_count = self.managed_body_indices.len();
+        for i in 0..managed_count {
+            let world_i = self.managed_body_indices[i];
+            let bodies = self.world.bodies();
+
+            if bodies[world_i].inv_mass <= 0.0 {
+                continue;
+            }
+
+            let velocity = bodies[world_i].velocity;
+            if velocity.length() < self.settings.ccd_velocity_threshold {
+                continue;
+            }
+
+            let extent_i = Self::smallest_extent(&bodies[world_i]);
+            let displacement = velocity * step_time;
+            if displacement.length() <= extent_i * 0.5 {
+                continue;
+            }
+
+            let start_position = bodies[world_i].position;
+            let mut t = 0.0;
+            let mut hit: Option<(Vector3, Vector3)> = None;
+
+            loop {
+                let position = start_position + velocity * t;
+                let mut closest_distance = f32::MAX;
+                let mut closest_dir = Vector3::zero();
+
+                for j in 0..managed_count {
+                    if i == j {
+                        continue;
+                    }
+                    let other = &bodies[self.managed_body_indices[j]];
+                    let to_other = other.position - position;
+                    let distance = to_other.length() - extent_i - Self::smallest_extent(other);
+                    if distance < closest_distance {
+                        closest_distance = distance;
+                        closest_dir = if to_other.length() > 1e-6 {
+                            to_other.normalize()
+                        } else {
+                            Vector3::new(0.0, 1.0, 0.0)
+                        };
+                    }
+                }
+
+                if closest_distance <= CONTACT_MARGIN {
+                    hit = Some((position, closest_dir * -1.0));
+                    break;
+                }
+
+                let v_close = velocity.dot(&closest_dir);
+                if v_close <= 0.0 {
+                    break; // not closing on the nearest candidate this step
+                }
+
+                t += closest_distance / v_close;
+                if t >= step_time {
+                    break;
+                }
+            }
+
+            if let Some((point, normal)) = hit {
+                self.world.bodies_mut()[world_i].position = point;
+                contacts.push((point, normal));
+
+                let record = self.tunnel_records.entry(i).or_insert_with(TunnelRecord::default);
+                if (record.dir - normal).length() < 0.05 {
+                    record.frames += 1;
+                } else {
+                    record.frames = 1;
+                    record.dir = normal;
+                }
+                if record.frames >= 2 {
+                    let vn = self.world.bodies()[world_i].velocity.dot(&normal);
+                    if vn < 0.0 {
+                        self.world.bodies_mut()[world_i].velocity -= normal * vn;
+                    }
+                }
+                resolved.push(i);
+            }
+        }
+
+        self.tunnel_records.retain(|index, _| resolved.contains(index));
+        self.toi_contacts = contacts;
+    }
+
+    /// Registers a managed body as a boids-style steering agent: from the next
+    /// `update` onward it steers itself each fixed step using `params`' weighted
+    /// separation/alignment/cohesion rules over neighbours within `neighbor_radius`
+    pub fn add_steering_agent(&mut self, body_index: usize, params: SteeringParams) {
+        self.steering_agents.push(SteeringAgent { body_index, params });
+    }
+
+    /// Applies one fixed step's worth of boids steering force to every
+    /// registered `SteeringAgent`. Neighbours are gathered via a uniform grid
+    /// hashed over the bodies at `managed_body_indices` (the same cell-hashing
+    /// scheme as `UniformGridBroadPhase`, kept separate since it only walks the
+    /// bodies this engine manages rather than all of `PhysicsWorld`'s bodies),
+    /// so this stays cheap as the crowd grows.
+    fn apply_steering_forces(&mut self) {
+        if self.steering_agents.is_empty() {
+            return;
+        }
+
+        let cell_size = self.settings.broadphase_cell_size.max(0.001);
+        let mut grid: HashMap<(i32, i32, i32), Vec<usize>> = HashMap::new();
+        let bodies = self.world.bodies();
+        for (i, &world_index) in self.managed_body_indices.iter().enumerate() {
+            grid.entry(Self::steering_cell_coord(bodies[world_index].position, cell_size))
+                .or_insert_with(Vec::new)
+                .push(i);
+        }
+
+        // Collect (force, max_speed) per body first, since computing a neighbour
+        // list borrows `bodies` immutably while another agent's body may itself
+        // be a neighbour.
+        let mut updates = Vec::with_capacity(self.steering_agents.len());
+        for agent in &self.steering_agents {
+            let world_index = match self.managed_body_indices.get(agent.body_index) {
+                Some(&idx) => idx,
+                None => continue,
+            };
+            let body = &bodies[world_index];
+            let position = body.position;
+            let velocity = body.velocity;
+            let radius = agent.params.neighbor_radius;
+
+            let mut separation = Vector3::zero();
+            let mut avg_velocity = Vector3::zero();
+            let mut centroid = Vector3::zero();
+            let mut neighbor_count = 0;
+
+            let cell = Self::steering_cell_coord(position, cell_size);
+            // Scan every cell the neighbour radius can reach, not just the adjacent ring
+            let reach = (radius / cell_size).ceil().max(1.0) as i32;
+            for dx in -reach..=reach {
+                for dy in -reach..=reach {
+                    for dz in -reach..=reach {
+                        let neighbor_cell = (cell.0 + dx, cell.1 + dy, cell.2 + dz);
+                        if let Some(indices) = grid.get(&neighbor_cell) {
+                            for &j in indices {
+                                if j == agent.body_index {
+                                    continue;
+                                }
+                                let other = &bodies[self.managed_body_indices[j]];
+                                let offset = position - other.position;
+                                let distance = offset.length();
+                                if distance < radius && distance > 1e-4 {
+                                    separation += offset.normalize() * (1.0 / distance);
+                                    avg_velocity += other.velocity;
+                                    centroid += other.position;
+                                    neighbor_count += 1;
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+
+            if neighbor_count == 0 {
+                continue;
+            }
+            let n = neighbor_count as f32;
+            avg_velocity = avg_velocity * (1.0 / n);
+            centroid = centroid * (1.0 / n);
+
+            let alignment = avg_velocity - velocity;
+            let cohesion = centroid - position;
+
+            let mut steer = separation * agent.params.separation_weight
+                + alignment * agent.params.alignment_weight
+                + cohesion * agent.params.cohesion_weight;
+            let steer_length = steer.length();
+            if steer_length > agent.params.max_force {
+                steer = steer.normalize() * agent.params.max_force;
+            }
+
+            updates.push((agent.body_index, steer, agent.params.max_speed));
+        }
+
+        for (body_index, force, max_speed) in updates {
+            let world_index = self.managed_body_indices[body_index];
+            let body = &mut self.world.bodies_mut()[world_index];
+            body.apply_force(force);
+            let speed = body.velocity.length();
+            if speed > max_speed {
+                body.velocity = body.velocity.normalize() * max_speed;
+            }
+        }
+    }
+
+    /// Hashes a position into the steering grid's integer cell coordinate,
+    /// mirroring `UniformGridBroadPhase::get_cell_coord`
+    fn steering_cell_coord(position: Vector3, cell_size: f32) -> (i32, i32, i32) {
+        (
+            (position.x / cell_size).floor() as i32,
+            (position.y / cell_size).floor() as i32,
+            (position.z / cell_size).floor() as i32,
+        )
+    }
+
     /// Creates a new rigid body
     pub fn create_rigid_body(&mut self) -> &mut RigidBody {
-        let body = Box::new(RigidBody::new());
-        self.managed_bodies.push(body);
-        self.world.add_body(*body);
-        self.managed_bodies.last_mut().unwrap()
+        let mut body = RigidBody::new();
+        body.collision_groups = self.settings.default_collision_groups;
+        self.world.add_body(body);
+        let world_index = self.world.bodies().len() - 1;
+        self.managed_body_indices.push(world_index);
+        &mut self.world.bodies_mut()[world_index]
     }
 
     /// Creates a box-shaped rigid body
@@ -134,6 +593,15 @@ impl PhysicsEngine {
         body
     }
 
+    /// Creates a box-shaped rigid body in the given collision groups, for bodies
+    /// that should selectively ignore each other (e.g. "collides with terrain but
+    /// not with other players' pickups")
+    pub fn create_box_in_group(&mut self, position: Vector3, size: Vector3, mass: f32, groups: CollisionGroups) -> &mut RigidBody {
+        let body = self.create_box(position, size, mass);
+        body.collision_groups = groups;
+        body
+    }
+
     /// Creates a sphere-shaped rigid body
     pub fn create_sphere(&mut self, position: Vector3, radius: f32, mass: f32) -> &mut RigidBody {
         let mut body = self.create_rigid_body();
@@ -144,6 +612,13 @@ impl PhysicsEngine {
         body
     }
 
+    /// Creates a sphere-shaped rigid body in the given collision groups
+    pub fn create_sphere_in_group(&mut self, position: Vector3, radius: f32, mass: f32, groups: CollisionGroups) -> &mut RigidBody {
+        let body = self.create_sphere(position, radius, mass);
+        body.collision_groups = groups;
+        body
+    }
+
     /// Creates a plane-shaped rigid body
     pub fn create_plane(&mut self, normal: Vector3, distance: f32, mass: f32) -> &mut RigidBody {
         let mut body = self.create_rigid_body();
@@ -249,66 +724,93 @@ impl PhysicsEngine {
             self.draw_contacts();
         }
         if self.settings.show_grid {
-            self.draw_grid();
+            if self.settings.show_broadphase_cells {
+                self.draw_broadphase_cells();
+            } else {
+                self.draw_grid();
+            }
         }
         self.draw_stats();
     }
 
     /// Draws colliders for all bodies
     fn draw_colliders(&mut self) {
-        for body in &self.managed_bodies {
-            let color = if body.inv_mass > 0.0 {
+        let tint_by_group = self.settings.tint_colliders_by_group;
+        let bodies = self.world.bodies();
+        for &world_index in &self.managed_body_indices {
+            let body = &bodies[world_index];
+            let color = if tint_by_group {
+                Self::collision_group_color(body.collision_groups.membership)
+            } else if body.inv_mass > 0.0 {
                 Vector3::new(0.0, 1.0, 0.0) // Dynamic: green
             } else {
                 Vector3::new(1.0, 0.0, 0.0) // Static: red
             };
 
+            // Push lines straight into debug_draw_data's Vec instead of going through
+            // draw_line: that takes &mut self, which would conflict with the `&self.world`
+            // borrow this loop holds via `bodies` for its whole lifetime.
             match body.shape {
                 CollisionShape::AABB => {
                     let min = body.position - body.half_extents;
                     let max = body.position + body.half_extents;
-                    
+
                     // Draw edges
-                    self.draw_line(Vector3::new(min.x, min.y, min.z), Vector3::new(max.x, min.y, min.z), Some(color));
-                    self.draw_line(Vector3::new(min.x, min.y, min.z), Vector3::new(min.x, max.y, min.z), Some(color));
-                    self.draw_line(Vector3::new(min.x, min.y, min.z), Vector3::new(min.x, min.y, max.z), Some(color));
-                    self.draw_line(Vector3::new(max.x, min.y, min.z), Vector3::new(max.x, max.y, min.z), Some(color));
-                    self.draw_line(Vector3::new(max.x, min.y, min.z), Vector3::new(max.x, min.y, max.z), Some(color));
-                    self.draw_line(Vector3::new(min.x, max.y, min.z), Vector3::new(max.x, max.y, min.z), Some(color));
-                    self.draw_line(Vector3::new(min.x, max.y, min.z), Vector3::new(min.x, max.y, max.z), Some(color));
-                    self.draw_line(Vector3::new(min.x, min.y, max.z), Vector3::new(max.x, min.y, max.z), Some(color));
-                    self.draw_line(Vector3::new(min.x, min.y, max.z), Vector3::new(min.x, max.y, max.z), Some(color));
-                    self.draw_line(Vector3::new(max.x, max.y, min.z), Vector3::new(max.x, max.y, max.z), Some(color));
-                    self.draw_line(Vector3::new(max.x, min.y, max.z), Vector3::new(max.x, max.y, max.z), Some(color));
-                    self.draw_line(Vector3::new(min.x, max.y, max.z), Vector3::new(max.x, max.y, max.z), Some(color));
+                    let lines = &mut self.debug_draw_data.lines;
+                    lines.push((Vector3::new(min.x, min.y, min.z), Vector3::new(max.x, min.y, min.z), color));
+                    lines.push((Vector3::new(min.x, min.y, min.z), Vector3::new(min.x, max.y, min.z), color));
+                    lines.push((Vector3::new(min.x, min.y, min.z), Vector3::new(min.x, min.y, max.z), color));
+                    lines.push((Vector3::new(max.x, min.y, min.z), Vector3::new(max.x, max.y, min.z), color));
+                    lines.push((Vector3::new(max.x, min.y, min.z), Vector3::new(max.x, min.y, max.z), color));
+                    lines.push((Vector3::new(min.x, max.y, min.z), Vector3::new(max.x, max.y, min.z), color));
+                    lines.push((Vector3::new(min.x, max.y, min.z), Vector3::new(min.x, max.y, max.z), color));
+                    lines.push((Vector3::new(min.x, min.y, max.z), Vector3::new(max.x, min.y, max.z), color));
+                    lines.push((Vector3::new(min.x, min.y, max.z), Vector3::new(min.x, max.y, max.z), color));
+                    lines.push((Vector3::new(max.x, max.y, min.z), Vector3::new(max.x, max.y, max.z), color));
+                    lines.push((Vector3::new(max.x, min.y, max.z), Vector3::new(max.x, max.y, max.z), color));
+                    lines.push((Vector3::new(min.x, max.y, max.z), Vector3::new(max.x, max.y, max.z), color));
                 }
                 CollisionShape::Sphere => {
                     let radius = body.half_extents.x;
                     let segments = 16;
+                    let lines = &mut self.debug_draw_data.lines;
                     for i in 0..segments {
                         let angle1 = (i as f32 / segments as f32) * 2.0 * PI;
                         let angle2 = ((i + 1) as f32 / segments as f32) * 2.0 * PI;
-                        
+
                         // Draw circles in XY plane
-                        self.draw_line(
+                        lines.push((
                             body.position + Vector3::new(angle1.cos() * radius, angle1.sin() * radius, 0.0),
                             body.position + Vector3::new(angle2.cos() * radius, angle2.sin() * radius, 0.0),
-                            Some(color)
-                        );
-                        
+                            color,
+                        ));
+
                         // Draw circles in XZ plane
-                        self.draw_line(
+                        lines.push((
                             body.position + Vector3::new(angle1.cos() * radius, 0.0, angle1.sin() * radius),
                             body.position + Vector3::new(angle2.cos() * radius, 0.0, angle2.sin() * radius),
-                            Some(color)
-                        );
-                        
+                            color,
+                        ));
+
                         // Draw circles in YZ plane
-                        self.draw_line(
+                        lines.push((
                             body.position + Vector3::new(0.0, angle1.cos() * radius, angle1.sin() * radius),
                             body.position + Vector3::new(0.0, angle2.cos() * radius, angle2.sin() * radius),
-                            Some(color)
-                        );
+                            color,
+                        ));
+                    }
+                }
+                CollisionShape::ConvexHull => {
+                    // No face/edge topology is stored (GJK/EPA only need a support
+                    // function), so draw a simple vertex loop as a stand-in wireframe.
+                    if let Some(hull) = &body.convex_hull {
+                        let verts = &hull.vertices;
+                        let lines = &mut self.debug_draw_data.lines;
+                        for i in 0..verts.len() {
+                            let a = body.position + verts[i];
+                            let b = body.position + verts[(i + 1) % verts.len()];
+                            lines.push((a, b, color));
+                        }
                     }
                 }
                 _ => {} // Other shapes not implemented yet
@@ -318,7 +820,11 @@ impl PhysicsEngine {
 
     /// Draws contact points
     fn draw_contacts(&mut self) {
-        // TODO: Implement contact point visualization
+        let contacts = self.toi_contacts.clone();
+        for (point, normal) in contacts {
+            self.draw_point(point, Some(Vector3::new(1.0, 1.0, 0.0)), Some(0.15));
+            self.draw_line(point, point + normal * 0.5, Some(Vector3::new(1.0, 1.0, 0.0)));
+        }
     }
 
     /// Draws the grid
@@ -346,12 +852,38 @@ impl PhysicsEngine {
         }
     }
 
+    /// Draws a wireframe box around each occupied broadphase grid cell, as an
+    /// alternative to the cosmetic floor grid for tuning `Settings.broadphase_cell_size`
+    fn draw_broadphase_cells(&mut self) {
+        let cell_size = self.world.broadphase_cell_size();
+        let color = Vector3::new(0.2, 0.6, 0.9);
+        let cells = self.world.occupied_broadphase_cells();
+
+        for cell in cells {
+            let min = Vector3::new(cell.x as f32, cell.y as f32, cell.z as f32) * cell_size;
+            let max = min + Vector3::new(cell_size, cell_size, cell_size);
+
+            self.draw_line(Vector3::new(min.x, min.y, min.z), Vector3::new(max.x, min.y, min.z), Some(color));
+            self.draw_line(Vector3::new(min.x, min.y, min.z), Vector3::new(min.x, max.y, min.z), Some(color));
+            self.draw_line(Vector3::new(min.x, min.y, min.z), Vector3::new(min.x, min.y, max.z), Some(color));
+            self.draw_line(Vector3::new(max.x, min.y, min.z), Vector3::new(max.x, max.y, min.z), Some(color));
+            self.draw_line(Vector3::new(max.x, min.y, min.z), Vector3::new(max.x, min.y, max.z), Some(color));
+            self.draw_line(Vector3::new(min.x, max.y, min.z), Vector3::new(max.x, max.y, min.z), Some(color));
+            self.draw_line(Vector3::new(min.x, max.y, min.z), Vector3::new(min.x, max.y, max.z), Some(color));
+            self.draw_line(Vector3::new(min.x, min.y, max.z), Vector3::new(max.x, min.y, max.z), Some(color));
+            self.draw_line(Vector3::new(min.x, min.y, max.z), Vector3::new(min.x, max.y, max.z), Some(color));
+            self.draw_line(Vector3::new(max.x, max.y, min.z), Vector3::new(max.x, max.y, max.z), Some(color));
+            self.draw_line(Vector3::new(max.x, min.y, max.z), Vector3::new(max.x, max.y, max.z), Some(color));
+            self.draw_line(Vector3::new(min.x, max.y, max.z), Vector3::new(max.x, max.y, max.z), Some(color));
+        }
+    }
+
     /// Draws simulation statistics
     fn draw_stats(&mut self) {
         let stats = format!(
             "FPS: {:.1}\nBodies: {}\nTime Step: {:.3}",
             self.average_fps(),
-            self.managed_bodies.len(),
+            self.managed_body_indices.len(),
             self.settings.fixed_time_step
         );
         self.draw_text(stats, Vector3::new(-10.0, 10.0, 0.0), None);
@@ -364,6 +896,28 @@ impl Default for PhysicsEngine {
     }
 }
 
+/// Normalized linear interpolation between two orientations, taking the
+/// shortest path (flipping `b` if it's in the opposite hemisphere of `a`).
+/// Cheaper than slerp and accurate enough for the small per-frame deltas
+/// `interpolated_transform` blends between.
+fn nlerp(a: Quaternion, b: Quaternion, t: f32) -> Quaternion {
+    let dot = a.w * b.w + a.x * b.x + a.y * b.y + a.z * b.z;
+    let b = if dot < 0.0 {
+        Quaternion::new(-b.w, -b.x, -b.y, -b.z)
+    } else {
+        b
+    };
+
+    let mut result = Quaternion::new(
+        a.w + (b.w - a.w) * t,
+        a.x + (b.x - a.x) * t,
+        a.y + (b.y - a.y) * t,
+        a.z + (b.z - a.z) * t,
+    );
+    result.normalize();
+    result
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
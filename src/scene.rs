@@ -0,0 +1,229 @@
+use std::fmt;
+
+/// A parsed JSON value, used to serialize and deserialize `PhysicsWorld` scenes.
+///
+/// This is intentionally minimal: it supports exactly the subset of JSON needed to
+/// round-trip a scene (objects, arrays, numbers, strings, bools, null) rather than
+/// depending on a general-purpose JSON crate.
+#[derive(Debug, Clone, PartialEq)]
+pub enum JsonValue {
+    Null,
+    Bool(bool),
+    Number(f64),
+    String(String),
+    Array(Vec<JsonValue>),
+    Object(Vec<(String, JsonValue)>),
+}
+
+impl JsonValue {
+    pub fn as_f32(&self) -> Option<f32> {
+        match self {
+            JsonValue::Number(n) => Some(*n as f32),
+            _ => None,
+        }
+    }
+
+    pub fn as_u32(&self) -> Option<u32> {
+        match self {
+            JsonValue::Number(n) => Some(*n as u32),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            JsonValue::String(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    pub fn as_array(&self) -> Option<&[JsonValue]> {
+        match self {
+            JsonValue::Array(items) => Some(items),
+            _ => None,
+        }
+    }
+
+    /// Looks up a key in this value if it is an object
+    pub fn get(&self, key: &str) -> Option<&JsonValue> {
+        match self {
+            JsonValue::Object(fields) => fields.iter().find(|(k, _)| k == key).map(|(_, v)| v),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for JsonValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            JsonValue::Null => write!(f, "null"),
+            JsonValue::Bool(b) => write!(f, "{}", b),
+            JsonValue::Number(n) => write!(f, "{}", n),
+            JsonValue::String(s) => write!(f, "\"{}\"", escape_string(s)),
+            JsonValue::Array(items) => {
+                write!(f, "[")?;
+                for (i, item) in items.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ",")?;
+                    }
+                    write!(f, "{}", item)?;
+                }
+                write!(f, "]")
+            }
+            JsonValue::Object(fields) => {
+                write!(f, "{{")?;
+                for (i, (key, value)) in fields.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ",")?;
+                    }
+                    write!(f, "\"{}\":{}", escape_string(key), value)?;
+                }
+                write!(f, "}}")
+            }
+        }
+    }
+}
+
+fn escape_string(s: &str) -> String {
+    s.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+/// Parses a JSON document into a `JsonValue`, or returns a description of the
+/// first parse error encountered.
+pub fn parse(input: &str) -> Result<JsonValue, String> {
+    let chars: Vec<char> = input.chars().collect();
+    let mut pos = 0;
+    let value = parse_value(&chars, &mut pos)?;
+    skip_whitespace(&chars, &mut pos);
+    Ok(value)
+}
+
+fn skip_whitespace(chars: &[char], pos: &mut usize) {
+    while *pos < chars.len() && chars[*pos].is_whitespace() {
+        *pos += 1;
+    }
+}
+
+fn parse_value(chars: &[char], pos: &mut usize) -> Result<JsonValue, String> {
+    skip_whitespace(chars, pos);
+    match chars.get(*pos) {
+        Some('{') => parse_object(chars, pos),
+        Some('[') => parse_array(chars, pos),
+        Some('"') => parse_string(chars, pos).map(JsonValue::String),
+        Some('t') => parse_literal(chars, pos, "true", JsonValue::Bool(true)),
+        Some('f') => parse_literal(chars, pos, "false", JsonValue::Bool(false)),
+        Some('n') => parse_literal(chars, pos, "null", JsonValue::Null),
+        Some(c) if c.is_ascii_digit() || *c == '-' => parse_number(chars, pos),
+        _ => Err(format!("unexpected character at position {}", pos)),
+    }
+}
+
+fn parse_literal(chars: &[char], pos: &mut usize, literal: &str, value: JsonValue) -> Result<JsonValue, String> {
+    let end = *pos + literal.len();
+    if end > chars.len() || chars[*pos..end].iter().collect::<String>() != literal {
+        return Err(format!("expected '{}' at position {}", literal, pos));
+    }
+    *pos = end;
+    Ok(value)
+}
+
+fn parse_number(chars: &[char], pos: &mut usize) -> Result<JsonValue, String> {
+    let start = *pos;
+    if chars.get(*pos) == Some(&'-') {
+        *pos += 1;
+    }
+    while chars.get(*pos).is_some_and(|c| c.is_ascii_digit() || *c == '.' || *c == 'e' || *c == 'E' || *c == '+' || *c == '-') {
+        *pos += 1;
+    }
+    let text: String = chars[start..*pos].iter().collect();
+    text.parse::<f64>().map(JsonValue::Number).map_err(|e| e.to_string())
+}
+
+fn parse_string(chars: &[char], pos: &mut usize) -> Result<String, String> {
+    if chars.get(*pos) != Some(&'"') {
+        return Err(format!("expected '\"' at position {}", pos));
+    }
+    *pos += 1;
+    let mut result = String::new();
+    loop {
+        match chars.get(*pos) {
+            Some('"') => {
+                *pos += 1;
+                return Ok(result);
+            }
+            Some('\\') => {
+                *pos += 1;
+                match chars.get(*pos) {
+                    Some('"') => result.push('"'),
+                    Some('\\') => result.push('\\'),
+                    Some('n') => result.push('\n'),
+                    Some('t') => result.push('\t'),
+                    Some(other) => result.push(*other),
+                    None => return Err("unterminated escape sequence".to_string()),
+                }
+                *pos += 1;
+            }
+            Some(c) => {
+                result.push(*c);
+                *pos += 1;
+            }
+            None => return Err("unterminated string".to_string()),
+        }
+    }
+}
+
+fn parse_array(chars: &[char], pos: &mut usize) -> Result<JsonValue, String> {
+    *pos += 1; // consume '['
+    let mut items = Vec::new();
+    skip_whitespace(chars, pos);
+    if chars.get(*pos) == Some(&']') {
+        *pos += 1;
+        return Ok(JsonValue::Array(items));
+    }
+    loop {
+        items.push(parse_value(chars, pos)?);
+        skip_whitespace(chars, pos);
+        match chars.get(*pos) {
+            Some(',') => {
+                *pos += 1;
+            }
+            Some(']') => {
+                *pos += 1;
+                return Ok(JsonValue::Array(items));
+            }
+            _ => return Err(format!("expected ',' or ']' at position {}", pos)),
+        }
+    }
+}
+
+fn parse_object(chars: &[char], pos: &mut usize) -> Result<JsonValue, String> {
+    *pos += 1; // consume '{'
+    let mut fields = Vec::new();
+    skip_whitespace(chars, pos);
+    if chars.get(*pos) == Some(&'}') {
+        *pos += 1;
+        return Ok(JsonValue::Object(fields));
+    }
+    loop {
+        skip_whitespace(chars, pos);
+        let key = parse_string(chars, pos)?;
+        skip_whitespace(chars, pos);
+        if chars.get(*pos) != Some(&':') {
+            return Err(format!("expected ':' at position {}", pos));
+        }
+        *pos += 1;
+        let value = parse_value(chars, pos)?;
+        fields.push((key, value));
+        skip_whitespace(chars, pos);
+        match chars.get(*pos) {
+            Some(',') => {
+                *pos += 1;
+            }
+            Some('}') => {
+                *pos += 1;
+                return Ok(JsonValue::Object(fields));
+            }
+            _ => return Err(format!("expected ',' or '}}' at position {}", pos)),
+        }
+    }
+}
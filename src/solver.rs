@@ -0,0 +1,178 @@
+//! Island-based parallel batching for the sequential-impulse contact solver.
+//!
+//! Gated behind the `parallel` feature, which pulls in:
+//! [dependencies]
+//! rayon = "1"
+//!
+//! `collision::solve_contact_constraints` already does warm-started, Baumgarte-biased
+//! sequential-impulse solving, but walks every constraint on one thread. This module sits
+//! on top of it: it groups a frame's constraints into islands (connected components of the
+//! body-contact graph, where a static body with `inv_mass == 0` doesn't connect the bodies
+//! on either side of it), then greedily graph-colors each island's constraints into batches
+//! where no two constraints in a batch share a dynamic body. Constraints within a batch
+//! touch disjoint bodies, so they can be solved across threads with `rayon` without
+//! data races, while islands are trivially independent of one another.
+
+use crate::aabb::RigidBody;
+use crate::collision::ContactConstraint;
+use rayon::prelude::*;
+
+/// Below this many constraints, an island is solved on the calling thread; spinning up
+/// rayon's work-stealing scheduler for a handful of constraints costs more than it saves.
+const PARALLEL_ISLAND_THRESHOLD: usize = 32;
+
+/// Finds the connected components of the body-contact graph among `constraints`, treating
+/// a body with `inv_mass == 0` as not propagating connectivity (so one static floor
+/// touching a hundred independent boxes doesn't merge them into one giant island).
+/// Returns, for each island, the indices into `constraints` that belong to it.
+fn build_islands(bodies: &[Box<RigidBody>], constraints: &[ContactConstraint]) -> Vec<Vec<usize>> {
+    let mut parent: Vec<usize> = (0..bodies.len()).collect();
+
+    fn find(parent: &mut [usize], i: usize) -> usize {
+        if parent[i] != i {
+            parent[i] = find(parent, parent[i]);
+        }
+        parent[i]
+    }
+
+    fn union(parent: &mut [usize], a: usize, b: usize) {
+        let (ra, rb) = (find(parent, a), find(parent, b));
+        if ra != rb {
+            parent[ra] = rb;
+        }
+    }
+
+    for constraint in constraints {
+        let (a, b) = (constraint.body_a, constraint.body_b);
+        if bodies[a].inv_mass > 0.0 && bodies[b].inv_mass > 0.0 {
+            union(&mut parent, a, b);
+        }
+    }
+
+    let mut islands: std::collections::HashMap<usize, Vec<usize>> = std::collections::HashMap::new();
+    for (index, constraint) in constraints.iter().enumerate() {
+        // A constraint touching a static body is keyed by its dynamic side's root; a
+        // constraint between two static bodies (both inv_mass == 0) can't move anything,
+        // but is still grouped under body_b's own singleton root so it isn't dropped.
+        let root = if bodies[constraint.body_a].inv_mass > 0.0 {
+            find(&mut parent, constraint.body_a)
+        } else {
+            find(&mut parent, constraint.body_b)
+        };
+        islands.entry(root).or_default().push(index);
+    }
+
+    islands.into_values().collect()
+}
+
+/// Greedily assigns each constraint index in `island` to the lowest-numbered batch whose
+/// existing members don't share a dynamic body with it, à la greedy graph coloring.
+fn color_batches(bodies: &[Box<RigidBody>], constraints: &[ContactConstraint], island: &[usize]) -> Vec<Vec<usize>> {
+    let mut batches: Vec<Vec<usize>> = Vec::new();
+    let mut batch_bodies: Vec<std::collections::HashSet<usize>> = Vec::new();
+
+    for &index in island {
+        let constraint = &constraints[index];
+        let dynamic_bodies: Vec<usize> = [constraint.body_a, constraint.body_b]
+            .into_iter()
+            .filter(|&b| bodies[b].inv_mass > 0.0)
+            .collect();
+
+        let slot = batch_bodies.iter().position(|used| {
+            dynamic_bodies.iter().all(|b| !used.contains(b))
+        });
+
+        match slot {
+            Some(slot) => {
+                batch_bodies[slot].extend(dynamic_bodies);
+                batches[slot].push(index);
+            }
+            None => {
+                batch_bodies.push(dynamic_bodies.into_iter().collect());
+                batches.push(vec![index]);
+            }
+        }
+    }
+
+    batches
+}
+
+/// Runs one warm-started, Baumgarte-biased velocity iteration over a single constraint,
+/// the same per-constraint math as `collision::solve_contact_constraints`'s inner loop.
+fn solve_one(bodies: &mut [Box<RigidBody>], constraint: &mut ContactConstraint, restitution: f32, dt: f32, beta: f32) {
+    crate::collision::collision::solve_contact_constraints(
+        bodies,
+        std::slice::from_mut(constraint),
+        restitution,
+        1,
+        dt,
+        beta,
+    );
+}
+
+/// A raw pointer known to be safe to send/share across the batch's worker threads: every
+/// batch produced by `color_batches` touches disjoint dynamic bodies, so no two threads
+/// ever write through the same index concurrently.
+struct BatchPtr<T>(*mut T, usize);
+
+// `#[derive(Copy)]` would add an implicit `T: Copy` bound, which `Box<RigidBody>` and
+// `ContactConstraint` don't satisfy even though copying the pointer/length pair itself
+// never touches `T`. Implement both by hand so `BatchPtr<T>` stays `Copy` for any `T`.
+impl<T> Clone for BatchPtr<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl<T> Copy for BatchPtr<T> {}
+
+unsafe impl<T> Send for BatchPtr<T> {}
+unsafe impl<T> Sync for BatchPtr<T> {}
+
+/// Solves all of `constraints` against `bodies` for `iterations` passes, batching each
+/// island's constraints by graph color so that batches with disjoint dynamic bodies run
+/// concurrently on rayon's thread pool. Islands below `PARALLEL_ISLAND_THRESHOLD`
+/// constraints are solved on the calling thread instead of paying scheduling overhead.
+pub fn solve_parallel(
+    bodies: &mut [Box<RigidBody>],
+    constraints: &mut [ContactConstraint],
+    restitution: f32,
+    iterations: u32,
+    dt: f32,
+    beta: f32,
+) {
+    let islands = build_islands(bodies, constraints);
+    let bodies_ptr = BatchPtr(bodies.as_mut_ptr(), bodies.len());
+    let constraints_ptr = BatchPtr(constraints.as_mut_ptr(), constraints.len());
+
+    for island in &islands {
+        let batches = color_batches(bodies, constraints, island);
+
+        for _ in 0..iterations {
+            for batch in &batches {
+                if island.len() < PARALLEL_ISLAND_THRESHOLD {
+                    for &index in batch {
+                        solve_one(bodies, &mut constraints[index], restitution, dt, beta);
+                    }
+                } else {
+                    // SAFETY: `color_batches` guarantees every constraint in `batch` touches a
+                    // disjoint set of dynamic bodies, so the `bodies` slice each closure
+                    // reconstructs from the shared raw pointer never aliases another
+                    // closure's writes; static bodies (inv_mass == 0) may be read by more
+                    // than one closure but are never written to by the solver.
+                    batch.par_iter().for_each(|&index| {
+                        // Rebind the whole wrapper before touching its fields: Rust 2021's
+                        // disjoint closure capture would otherwise capture `bodies_ptr.0`/`.1`
+                        // as bare `*mut` fields instead of the `BatchPtr` itself, and raw
+                        // pointers aren't Send/Sync on their own — only `BatchPtr`'s unsafe
+                        // impls make this closure shareable across threads.
+                        let bodies_ptr = bodies_ptr;
+                        let constraints_ptr = constraints_ptr;
+                        let bodies: &mut [Box<RigidBody>] = unsafe { std::slice::from_raw_parts_mut(bodies_ptr.0, bodies_ptr.1) };
+                        let constraint = unsafe { &mut *constraints_ptr.0.add(index) };
+                        solve_one(bodies, constraint, restitution, dt, beta);
+                    });
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,76 @@
+use crate::math_utils::Vector3;
+use crate::aabb::RigidBody;
+use crate::broad_phase::UniformGridBroadPhase;
+
+/// Per-axis weights and limits for the three classic boid rules: separation,
+/// alignment, and cohesion
+#[derive(Debug, Clone, Copy)]
+pub struct SteeringParams {
+    pub neighbor_radius: f32,     // Other bodies farther than this are ignored
+    pub separation_weight: f32,   // Steer away from nearby bodies' average offset
+    pub alignment_weight: f32,    // Steer velocity toward neighbours' average velocity
+    pub cohesion_weight: f32,     // Steer toward neighbours' centroid
+    pub max_force: f32,           // Clamp on the combined steering force
+}
+
+impl Default for SteeringParams {
+    fn default() -> Self {
+        Self {
+            neighbor_radius: 5.0,
+            separation_weight: 1.5,
+            alignment_weight: 1.0,
+            cohesion_weight: 1.0,
+            max_force: 10.0,
+        }
+    }
+}
+
+/// Computes the combined boid steering force for `bodies[index]` from its
+/// neighbors within `params.neighbor_radius` (found via `broad_phase.query_radius`,
+/// so only bodies sharing a grid cell with `bodies[index]` are considered;
+/// `broad_phase` must already have been `update`d this frame), clamped to
+/// `params.max_force`.
+/// Feed the result into `bodies[index].apply_force(...)`.
+pub fn compute_steering_force(
+    index: usize,
+    bodies: &[Box<RigidBody>],
+    broad_phase: &UniformGridBroadPhase,
+    params: &SteeringParams,
+) -> Vector3 {
+    let neighbors = broad_phase.query_radius(bodies, index, params.neighbor_radius);
+    if neighbors.is_empty() {
+        return Vector3::zero();
+    }
+
+    let position = bodies[index].position;
+    let velocity = bodies[index].velocity;
+
+    let mut separation = Vector3::zero();
+    let mut velocity_sum = Vector3::zero();
+    let mut position_sum = Vector3::zero();
+
+    for &other in &neighbors {
+        let offset = position - bodies[other].position;
+        let distance = offset.length();
+        if distance > 0.0 {
+            separation += offset.normalize() * (1.0 / distance);
+        }
+        velocity_sum += bodies[other].velocity;
+        position_sum += bodies[other].position;
+    }
+
+    let count = neighbors.len() as f32;
+    let alignment = velocity_sum * (1.0 / count) - velocity;
+    let cohesion = position_sum * (1.0 / count) - position;
+
+    let force = separation * params.separation_weight
+        + alignment * params.alignment_weight
+        + cohesion * params.cohesion_weight;
+
+    let force_len = force.length();
+    if force_len > params.max_force {
+        force * (params.max_force / force_len)
+    } else {
+        force
+    }
+}
@@ -1,15 +1,48 @@
+use crate::math_utils::math_utils::{calculate_kinetic_energy, calculate_rotational_kinetic_energy, calculate_potential_energy};
 use crate::math_utils::Vector3;
 use crate::aabb::{RigidBody, CollisionShape};
 use crate::constraints::Constraint;
-use crate::collision::{AABB, OBB, compute_aabb_collision, compute_obb_collision, compute_obb_aabb_collision, compute_sphere_collision, resolve_sphere_collision};
-use crate::collision::collision::{resolve_aabb_collision, resolve_obb_collision, resolve_obb_aabb_collision};
+use crate::collision::{AABB, OBB, ContactCache, ContactConstraint, CollisionEvent, ContactPhase, compute_aabb_collision, compute_obb_collision, compute_obb_aabb_collision, compute_convex_collision, generate_obb_manifold, warm_start_manifold};
+use crate::collision::collision::{sphere_vs_sphere, resolve_sphere_sphere, resolve_aabb_collision, resolve_obb_aabb_collision, resolve_convex_collision, solve_manifold_pair, solve_contact_constraints};
+use crate::mesh::sphere_vs_mesh;
+use crate::constraints::ConstraintDescriptor;
+use crate::scene::JsonValue;
+use crate::broad_phase::{UniformGridBroadPhase, GridCoord};
+use std::collections::{HashMap, HashSet};
 use std::vec::Vec;
 
+/// Selects which integration/constraint-solving strategy `PhysicsWorld::step` uses
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SolverMode {
+    /// Semi-implicit Euler integration with velocity-impulse constraint/contact resolution
+    Impulse,
+    /// Position-based dynamics (XPBD) with substepping, for stiff constraints and stacks
+    Xpbd,
+    /// Classic RK4 integration of the force-driven (gravity + current accumulated forces) part
+    /// of the state, followed by the usual velocity-impulse constraint/contact resolution
+    Rk4,
+}
+
 /// Manages a collection of RigidBody objects and performs physics simulation
 pub struct PhysicsWorld {
     bodies: Vec<Box<RigidBody>>,
     constraints: Vec<Box<dyn Constraint>>,
     fixed_delta_time: f32,
+    solver_mode: SolverMode,
+    substep_count: u32,
+    continuous_detection: bool,
+    gravity: Vector3,
+    broad_phase: UniformGridBroadPhase,
+    contact_cache: ContactCache,
+    collision_events: Vec<CollisionEvent>,
+    active_contacts: HashMap<(usize, usize), CollisionEvent>,
+    solver_iterations: u32,
+    contact_bias_factor: f32,
+    contact_constraints: HashMap<(usize, usize), (f32, [f32; 2])>,
+    collision_callbacks: Vec<Box<dyn FnMut(&CollisionEvent)>>,
+    global_linear_damping: f32,
+    global_angular_damping: f32,
+    accumulator: f32,
 }
 
 impl PhysicsWorld {
@@ -19,7 +52,237 @@ impl PhysicsWorld {
             bodies: Vec::new(),
             constraints: Vec::new(),
             fixed_delta_time: 1.0 / 60.0, // Default: 1/60 seconds
+            solver_mode: SolverMode::Impulse,
+            substep_count: 10,
+            continuous_detection: false,
+            gravity: Vector3::new(0.0, -9.8, 0.0),
+            broad_phase: UniformGridBroadPhase::new(4.0),
+            contact_cache: ContactCache::new(),
+            collision_events: Vec::new(),
+            active_contacts: HashMap::new(),
+            solver_iterations: 8,
+            contact_bias_factor: 0.2,
+            contact_constraints: HashMap::new(),
+            collision_callbacks: Vec::new(),
+            global_linear_damping: 0.0,
+            global_angular_damping: 0.0,
+            accumulator: 0.0,
+        }
+    }
+
+    /// The maximum number of `step()` calls a single `advance` will run, so a long
+    /// stall (e.g. the app was paused) can't spiral into simulating hours of catch-up
+    const MAX_STEPS_PER_ADVANCE: u32 = 8;
+
+    /// Decouples the simulation's fixed timestep from the caller's frame time: accumulates
+    /// `real_dt` and runs as many `step()` calls as fit, leaving any leftover time in the
+    /// accumulator for the next call. Callers with a variable-rate render loop should call
+    /// this once per frame instead of `step()` directly.
+    pub fn advance(&mut self, real_dt: f32) {
+        self.accumulator += real_dt;
+
+        let mut steps_run = 0;
+        while self.accumulator >= self.fixed_delta_time && steps_run < Self::MAX_STEPS_PER_ADVANCE {
+            self.step();
+            self.accumulator -= self.fixed_delta_time;
+            steps_run += 1;
         }
+
+        // A stall long enough to exhaust the step cap would otherwise keep growing the
+        // accumulator forever; clamp it back down to at most one more fixed step of debt
+        if steps_run == Self::MAX_STEPS_PER_ADVANCE {
+            self.accumulator = self.accumulator.min(self.fixed_delta_time);
+        }
+    }
+
+    /// How far between the previous and current fixed step the leftover accumulator
+    /// time sits, in `[0, 1]` (1 only when `advance` hit its step cap and left a full
+    /// fixed step banked). Renderers can use this to interpolate body transforms
+    /// for smooth visuals at a frame rate that doesn't match `fixed_delta_time`.
+    pub fn interpolation_alpha(&self) -> f32 {
+        self.accumulator / self.fixed_delta_time
+    }
+
+    /// Sets the default linear/angular damping applied to bodies that leave their own
+    /// `linear_damping`/`angular_damping` at zero, so a whole scene can bleed off energy
+    /// without having to configure every body individually
+    pub fn set_global_damping(&mut self, linear: f32, angular: f32) {
+        self.global_linear_damping = linear;
+        self.global_angular_damping = angular;
+    }
+
+    /// Takes every `CollisionEvent` collected by the most recent `step` call, leaving the
+    /// internal buffer empty. Call this once per frame after `step` to react to contacts
+    /// ("A hit B with impulse X") without polling body state yourself.
+    pub fn drain_collision_events(&mut self) -> Vec<CollisionEvent> {
+        std::mem::take(&mut self.collision_events)
+    }
+
+    /// Registers a callback invoked with every `CollisionEvent` as it's recorded during
+    /// `step`, for games that want to react to contacts immediately (play a sound, apply
+    /// damage) instead of polling `drain_collision_events` once per frame.
+    pub fn on_collision(&mut self, callback: Box<dyn FnMut(&CollisionEvent)>) {
+        self.collision_callbacks.push(callback);
+    }
+
+    /// Records a resolved contact between `i` and `j` as a `CollisionEvent`, tagged
+    /// `Enter` the first step the pair touches and `Stay` on every step after, using
+    /// `active_contacts` as the persistent record of what was touching last step.
+    /// `touched_this_step` accumulates the pair so `end_collision_events` can tell which
+    /// previously-active pairs broke contact this step.
+    fn record_contact(
+        &mut self,
+        i: usize,
+        j: usize,
+        point: Vector3,
+        normal: Vector3,
+        penetration: f32,
+        impulse: f32,
+        touched_this_step: &mut HashSet<(usize, usize)>,
+    ) {
+        let key = if i < j { (i, j) } else { (j, i) };
+        let phase = if self.active_contacts.contains_key(&key) {
+            ContactPhase::Stay
+        } else {
+            ContactPhase::Enter
+        };
+        let event = CollisionEvent { body_a: i, body_b: j, point, normal, penetration, impulse, phase };
+        self.active_contacts.insert(key, event);
+        touched_this_step.insert(key);
+        self.emit_event(event);
+    }
+
+    /// Pushes an event onto the drainable buffer and notifies every registered
+    /// `on_collision` callback
+    fn emit_event(&mut self, event: CollisionEvent) {
+        for callback in self.collision_callbacks.iter_mut() {
+            callback(&event);
+        }
+        self.collision_events.push(event);
+    }
+
+    /// Emits an `Exit` event for every pair in `active_contacts` that wasn't touched this
+    /// step, using each pair's last known contact geometry, then forgets it.
+    fn end_collision_events(&mut self, touched_this_step: &HashSet<(usize, usize)>) {
+        let ended: Vec<(usize, usize)> = self.active_contacts.keys()
+            .filter(|key| !touched_this_step.contains(*key))
+            .cloned()
+            .collect();
+        for key in ended {
+            if let Some(mut event) = self.active_contacts.remove(&key) {
+                event.phase = ContactPhase::Exit;
+                event.impulse = 0.0;
+                self.emit_event(event);
+            }
+        }
+    }
+
+    /// Gets the gravitational acceleration applied to dynamic bodies each step
+    pub fn gravity(&self) -> Vector3 {
+        self.gravity
+    }
+
+    /// Sets the gravitational acceleration applied to dynamic bodies each step
+    pub fn set_gravity(&mut self, gravity: Vector3) {
+        self.gravity = gravity;
+    }
+
+    /// Gets whether continuous (swept) collision detection is enabled
+    pub fn continuous_detection(&self) -> bool {
+        self.continuous_detection
+    }
+
+    /// Enables/disables conservative-advancement swept collision detection for fast
+    /// bodies, to stop tunneling through thin geometry at large timesteps
+    pub fn set_continuous_detection(&mut self, enabled: bool) {
+        self.continuous_detection = enabled;
+    }
+
+    /// Gets the current solver mode
+    pub fn solver_mode(&self) -> SolverMode {
+        self.solver_mode
+    }
+
+    /// Selects the integration/constraint-solving strategy used by `step`
+    pub fn set_solver_mode(&mut self, mode: SolverMode) {
+        self.solver_mode = mode;
+    }
+
+    /// Gets the number of XPBD substeps per `step` call
+    pub fn substep_count(&self) -> u32 {
+        self.substep_count
+    }
+
+    /// Sets the number of XPBD substeps per `step` call (recommended: 8-20)
+    pub fn set_substep_count(&mut self, count: u32) {
+        self.substep_count = count.max(1);
+    }
+
+    /// Gets the number of velocity iterations the sequential-impulse contact
+    /// solver runs per `step` call
+    pub fn solver_iterations(&self) -> u32 {
+        self.solver_iterations
+    }
+
+    /// Sets the number of velocity iterations the sequential-impulse contact solver
+    /// runs per `step` call (default 8; more iterations converge stacks faster at
+    /// the cost of per-step work)
+    pub fn set_solver_iterations(&mut self, iterations: u32) {
+        self.solver_iterations = iterations.max(1);
+    }
+
+    /// Gets the Baumgarte stabilization factor (β) used to bias penetrating contacts'
+    /// target velocity back apart during the sequential-impulse solve
+    pub fn contact_bias_factor(&self) -> f32 {
+        self.contact_bias_factor
+    }
+
+    /// Sets the Baumgarte stabilization factor (β, default 0.2). Higher values correct
+    /// penetration faster at the cost of more visible velocity "pop"; lower values settle
+    /// more gently but let bodies sink further before the solver pushes them back out.
+    pub fn set_contact_bias_factor(&mut self, beta: f32) {
+        self.contact_bias_factor = beta.max(0.0);
+    }
+
+    /// Gets the broadphase grid cell size
+    pub fn broadphase_cell_size(&self) -> f32 {
+        self.broad_phase.cell_size()
+    }
+
+    /// Sets the broadphase grid cell size. Larger cells mean fewer, bigger
+    /// buckets (good for sparse scenes); smaller cells mean more precise culling
+    /// (good for dense scenes of small bodies).
+    pub fn set_broadphase_cell_size(&mut self, cell_size: f32) {
+        self.broad_phase.set_cell_size(cell_size);
+    }
+
+    /// The grid coordinates of every occupied broadphase cell this step, for
+    /// visualizing broadphase occupancy
+    pub fn occupied_broadphase_cells(&self) -> Vec<GridCoord> {
+        self.broad_phase.occupied_cells()
+    }
+
+    /// Below this body count, all-pairs iteration is cheaper than the overhead of
+    /// rebuilding the grid and is guaranteed not to miss anything regardless of cell size
+    const SMALL_SCENE_BODY_COUNT: usize = 32;
+
+    /// Rebuilds the broadphase grid from the current body positions and returns
+    /// the candidate colliding pairs, replacing all-pairs (i, j) iteration with a
+    /// scan over just the bodies that share a grid cell. Falls back to all-pairs
+    /// directly for small scenes, where grid overhead isn't worth paying.
+    fn broadphase_pairs(&mut self) -> Vec<(usize, usize)> {
+        if self.bodies.len() < Self::SMALL_SCENE_BODY_COUNT {
+            let n = self.bodies.len();
+            let mut pairs = Vec::with_capacity(n * n / 2);
+            for i in 0..n {
+                for j in (i + 1)..n {
+                    pairs.push((i, j));
+                }
+            }
+            return pairs;
+        }
+        self.broad_phase.update(&self.bodies);
+        self.broad_phase.get_potential_pairs()
     }
 
     /// Adds a RigidBody to the simulation
@@ -32,15 +295,307 @@ impl PhysicsWorld {
         self.constraints.push(constraint);
     }
 
-    /// Advances the simulation by one fixed timestep
+    /// Advances the simulation by one fixed timestep, using the selected solver mode
     pub fn step(&mut self) {
+        match self.solver_mode {
+            SolverMode::Impulse => self.step_impulse(),
+            SolverMode::Xpbd => self.step_xpbd(),
+            SolverMode::Rk4 => self.step_rk4(),
+        }
+    }
+
+    /// Advances the simulation using classic RK4 integration of each body's
+    /// gravity-driven motion, then solves constraints and contacts as usual.
+    ///
+    /// The acceleration field (gravity plus each body's currently accumulated force) is
+    /// snapshotted once per step and treated as constant across the four RK4 stages, per
+    /// a force-field evaluation; this is exact for constant accelerations and reduces
+    /// integration error for free (unconstrained) motion over semi-implicit Euler.
+    fn step_rk4(&mut self) {
+        let gravity = self.gravity;
+        let dt = self.fixed_delta_time;
+
+        for body in &mut self.bodies {
+            if body.inv_mass <= 0.0 {
+                continue;
+            }
+            let accel = gravity + body.force_accum * body.inv_mass;
+
+            // State derivative is constant (dv/dt = accel, dx/dt = v + t*accel), so the
+            // four RK4 stages reduce to evaluating that derivative at t=0, dt/2, dt/2, dt
+            let k1_v = accel;
+            let k1_x = body.velocity;
+
+            let k2_v = accel;
+            let k2_x = body.velocity + k1_v * (dt * 0.5);
+
+            let k3_v = accel;
+            let k3_x = body.velocity + k2_v * (dt * 0.5);
+
+            let k4_v = accel;
+            let k4_x = body.velocity + k3_v * dt;
+
+            body.position += (k1_x + k2_x * 2.0 + k3_x * 2.0 + k4_x) * (dt / 6.0);
+            body.velocity += (k1_v + k2_v * 2.0 + k3_v * 2.0 + k4_v) * (dt / 6.0);
+            body.clear_forces();
+        }
+
+        self.solve_constraints_and_contacts(dt);
+    }
+
+    /// Solves constraints then detects/resolves contacts; the tail of `step_rk4` once
+    /// bodies have been integrated for the step (`step_impulse` runs its own contact
+    /// pass through `solve_contact_constraints` instead).
+    fn solve_constraints_and_contacts(&mut self, dt: f32) {
+        for constraint in &mut self.constraints {
+            constraint.pre_solve(dt);
+            constraint.solve(dt);
+            constraint.post_solve();
+        }
+
+        let restitution = 0.5;
+        let friction = 0.4;
+        let mut touched_this_step = HashSet::new();
+
+        for (i, j) in self.broadphase_pairs() {
+            let (body_a, body_b) = self.bodies.split_at_mut(i + 1);
+            let body_a = &mut body_a[i];
+            let body_b = &mut body_b[j - (i + 1)];
+
+            if body_a.inv_mass == 0.0 && body_b.inv_mass == 0.0 {
+                continue;
+            }
+
+            if !body_a.should_collide(body_b) {
+                continue;
+            }
+
+            let midpoint = (body_a.position + body_b.position) * 0.5;
+
+            match (body_a.shape, body_b.shape) {
+                (CollisionShape::Sphere, CollisionShape::Sphere) => {
+                    if let Some((penetration, normal)) = sphere_vs_sphere(body_a, body_b) {
+                        let impulse = resolve_sphere_sphere(body_a, body_b, normal, penetration, restitution, friction);
+                        self.record_contact(i, j, midpoint, normal, penetration, impulse, &mut touched_this_step);
+                    }
+                }
+                (CollisionShape::AABB, CollisionShape::AABB) => {
+                    let aabb_a = AABB::from_rigid_body(body_a);
+                    let aabb_b = AABB::from_rigid_body(body_b);
+                    if let Some((penetration, normal)) = compute_aabb_collision(&aabb_a, &aabb_b) {
+                        let impulse = resolve_aabb_collision(body_a, body_b, normal, penetration, restitution, friction);
+                        self.record_contact(i, j, midpoint, normal, penetration, impulse, &mut touched_this_step);
+                    }
+                }
+                (CollisionShape::OBB, CollisionShape::OBB) => {
+                    let obb_a = OBB::from_rigid_body(body_a);
+                    let obb_b = OBB::from_rigid_body(body_b);
+                    // Resolve through a persistent, warm-started contact manifold
+                    // rather than a single-point impulse, so stacked boxes settle
+                    // instead of jittering
+                    if let Some(mut manifold) = generate_obb_manifold(&obb_a, &obb_b) {
+                        if let Some(previous) = self.contact_cache.get(i, j) {
+                            warm_start_manifold(&mut manifold, previous, 0.02);
+                        }
+                        let impulse = solve_manifold_pair(body_a, body_b, &mut manifold, restitution, 8);
+                        let normal = manifold.normal;
+                        let penetration = manifold.points.iter().map(|p| p.penetration).fold(0.0, f32::max);
+                        self.contact_cache.store(i, j, manifold);
+                        self.record_contact(i, j, midpoint, normal, penetration, impulse, &mut touched_this_step);
+                    } else {
+                        self.contact_cache.remove(i, j);
+                    }
+                }
+                (CollisionShape::OBB, CollisionShape::AABB) | (CollisionShape::AABB, CollisionShape::OBB) => {
+                    let (obb, aabb) = if body_a.shape == CollisionShape::OBB {
+                        (OBB::from_rigid_body(body_a), AABB::from_rigid_body(body_b))
+                    } else {
+                        (OBB::from_rigid_body(body_b), AABB::from_rigid_body(body_a))
+                    };
+                    if let Some((penetration, normal)) = compute_obb_aabb_collision(&obb, &aabb) {
+                        let impulse = resolve_obb_aabb_collision(body_a, body_b, normal, penetration, restitution, friction);
+                        self.record_contact(i, j, midpoint, normal, penetration, impulse, &mut touched_this_step);
+                    }
+                }
+                (CollisionShape::Sphere, CollisionShape::TriangleMesh)
+                | (CollisionShape::TriangleMesh, CollisionShape::Sphere) => {
+                    Self::resolve_sphere_mesh(body_a, body_b, restitution, friction);
+                }
+                (CollisionShape::ConvexHull, CollisionShape::ConvexHull) => {
+                    if let Some((penetration, normal)) = compute_convex_collision(body_a, body_b) {
+                        let impulse = resolve_convex_collision(body_a, body_b, normal, penetration, restitution, friction);
+                        self.record_contact(i, j, midpoint, normal, penetration, impulse, &mut touched_this_step);
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        self.end_collision_events(&touched_this_step);
+    }
+
+    /// Resolves a sphere-vs-static-triangle-mesh contact, if any. `a`/`b` may be
+    /// given in either order; whichever carries `CollisionShape::TriangleMesh` must
+    /// have its `mesh` field populated, or this is a no-op.
+    fn resolve_sphere_mesh(a: &mut RigidBody, b: &mut RigidBody, restitution: f32, friction: f32) {
+        let a_is_sphere = a.shape == CollisionShape::Sphere;
+        let (sphere, mesh_body) = if a_is_sphere { (&*a, &*b) } else { (&*b, &*a) };
+
+        let contact = match &mesh_body.mesh {
+            Some(mesh) => sphere_vs_mesh(sphere.position, sphere.radius, mesh),
+            None => None,
+        };
+
+        if let Some(contact) = contact {
+            // `contact.normal` points away from the mesh surface, i.e. from b to a
+            // when a is the sphere; `resolve_sphere_sphere` expects a normal
+            // pointing from its first argument toward its second.
+            let normal = if a_is_sphere { contact.normal * -1.0 } else { contact.normal };
+            resolve_sphere_sphere(a, b, normal, contact.penetration, restitution, friction);
+        }
+    }
+
+    /// Computes the total mechanical energy (kinetic + gravitational potential) of the
+    /// scene, for use as an energy-conservation diagnostic on constraint/force-only
+    /// scenes. Contacts resolved with restitution < 1 legitimately dissipate energy, so
+    /// this is not expected to stay constant across steps that involve bouncing contacts.
+    pub fn total_energy(&self) -> f32 {
+        let gravity_magnitude = self.gravity.length();
+        self.bodies.iter().map(|body| {
+            let kinetic = calculate_kinetic_energy(body.mass, body.velocity)
+                + calculate_rotational_kinetic_energy(body.inertia_tensor, body.angular_velocity);
+            let potential = calculate_potential_energy(body.mass, body.position.y, gravity_magnitude);
+            kinetic + potential
+        }).sum()
+    }
+
+    /// Advances the simulation using position-based dynamics (XPBD) substepping
+    ///
+    /// Splits `fixed_delta_time` into `substep_count` substeps. Each substep integrates
+    /// velocities with gravity, predicts positions, runs a Gauss-Seidel positional
+    /// correction pass over pairwise contacts (compliance 0, i.e. rigid), then recovers
+    /// velocities from the position delta before handing off to the existing
+    /// velocity-based `Constraint` solve for joints.
+    fn step_xpbd(&mut self) {
+        let gravity = self.gravity;
+        let h = self.fixed_delta_time / self.substep_count as f32;
+
+        for _ in 0..self.substep_count {
+            let mut prev_positions = Vec::with_capacity(self.bodies.len());
+            for body in &mut self.bodies {
+                if body.inv_mass > 0.0 {
+                    body.velocity += gravity * h;
+                }
+                prev_positions.push(body.position);
+                body.position += body.velocity * h;
+            }
+
+            // Gauss-Seidel positional correction over pairwise contacts (rigid, compliance 0)
+            for (i, j) in self.broadphase_pairs() {
+                let (body_a, body_b) = self.bodies.split_at_mut(i + 1);
+                let body_a = &mut body_a[i];
+                let body_b = &mut body_b[j - (i + 1)];
+
+                if body_a.inv_mass == 0.0 && body_b.inv_mass == 0.0 {
+                    continue;
+                }
+
+                if !body_a.should_collide(body_b) {
+                    continue;
+                }
+
+                if let Some((c, normal)) = Self::contact_error(body_a, body_b) {
+                    let w_sum = body_a.inv_mass + body_b.inv_mass;
+                    if w_sum <= 0.0 {
+                        continue;
+                    }
+                    let delta_lambda = -c / w_sum;
+                    body_a.position -= normal * (delta_lambda * body_a.inv_mass);
+                    body_b.position += normal * (delta_lambda * body_b.inv_mass);
+                }
+            }
+
+            // Recover velocities from the position delta accumulated this substep
+            for (body, prev_position) in self.bodies.iter_mut().zip(prev_positions.iter()) {
+                if body.inv_mass > 0.0 {
+                    body.velocity = (body.position - *prev_position) / h;
+                }
+            }
+
+            // Joint constraints still solve on velocities, using the substep as their dt
+            for constraint in &mut self.constraints {
+                constraint.pre_solve(h);
+                constraint.solve(h);
+                constraint.post_solve();
+            }
+        }
+    }
+
+    /// Solves the conservative-advancement time of impact for two spheres, given their
+    /// relative position `p = p_a - p_b` and relative velocity `u = v_a - v_b` at the
+    /// start of the step. Returns the smallest `tau` in `[0, 1]` such that
+    /// `|p + tau * u * dt|^2 == combined_radius^2`, i.e. the fraction of the step at
+    /// which the spheres first touch, or `None` if they never do.
+    fn sphere_sphere_toi(p: Vector3, u: Vector3, combined_radius: f32, dt: f32) -> Option<f32> {
+        let ud = u * dt;
+        let a = ud.dot(&ud);
+        let b = 2.0 * p.dot(&ud);
+        let c = p.dot(&p) - combined_radius * combined_radius;
+
+        if a.abs() < 1e-12 {
+            return None;
+        }
+
+        let discriminant = b * b - 4.0 * a * c;
+        if discriminant < 0.0 {
+            return None;
+        }
+
+        let sqrt_d = discriminant.sqrt();
+        let tau1 = (-b - sqrt_d) / (2.0 * a);
+        let tau2 = (-b + sqrt_d) / (2.0 * a);
+        let tau_min = tau1.min(tau2);
+        let tau_max = tau1.max(tau2);
+
+        if (0.0..=1.0).contains(&tau_min) {
+            Some(tau_min)
+        } else if (0.0..=1.0).contains(&tau_max) {
+            Some(tau_max)
+        } else {
+            None
+        }
+    }
+
+    /// Computes the contact constraint error `C` (minus the penetration depth, so
+    /// negative = overlapping) and world-space normal pointing from `a` to `b` for a
+    /// contact pair, if any, for use by the XPBD pass
+    fn contact_error(a: &RigidBody, b: &RigidBody) -> Option<(f32, Vector3)> {
+        match (a.shape, b.shape) {
+            (CollisionShape::Sphere, CollisionShape::Sphere) => {
+                sphere_vs_sphere(a, b).map(|(pen, n)| (-pen, n))
+            }
+            (CollisionShape::AABB, CollisionShape::AABB) => {
+                let aabb_a = AABB::from_rigid_body(a);
+                let aabb_b = AABB::from_rigid_body(b);
+                compute_aabb_collision(&aabb_a, &aabb_b).map(|(pen, n)| (-pen, n))
+            }
+            _ => None,
+        }
+    }
+
+    /// Advances the simulation by one fixed timestep using semi-implicit Euler
+    /// integration and velocity-impulse constraint/contact resolution
+    fn step_impulse(&mut self) {
         // 1) Apply gravity to all bodies
-        let gravity = Vector3::new(0.0, -9.8, 0.0);
+        let gravity = self.gravity;
         self.apply_global_force(gravity);
 
+        // Snapshot pre-integration positions, needed by swept collision detection
+        let prev_positions: Vec<Vector3> = self.bodies.iter().map(|b| b.position).collect();
+
         // 2) Integrate each body over the fixed timestep
         for body in &mut self.bodies {
-            body.integrate(self.fixed_delta_time);
+            body.integrate_with_damping_defaults(self.fixed_delta_time, self.global_linear_damping, self.global_angular_damping);
         }
 
         // 3) Solve constraints
@@ -50,61 +605,160 @@ impl PhysicsWorld {
             constraint.post_solve();
         }
 
-        // 4) Detect and resolve collisions
+        // 4) Detect collisions, then resolve every contact together through the
+        // warm-started sequential-impulse solver (`solve_contact_constraints`) so later
+        // contacts in a stack don't undo the positional correction of earlier ones.
         let restitution = 0.5;    // Coefficient of restitution
-        let friction = 0.4;       // Friction coefficient
+        let mut touched_this_step = HashSet::new();
+        let mut pending: Vec<(usize, usize, Vector3)> = Vec::new(); // (i, j, midpoint)
+        let mut constraints: Vec<ContactConstraint> = Vec::new();
 
-        // Check all pairs of bodies for collisions
-        for i in 0..self.bodies.len() {
-            for j in (i + 1)..self.bodies.len() {
-                let (body_a, body_b) = self.bodies.split_at_mut(i + 1);
-                let body_a = &mut body_a[i];
-                let body_b = &mut body_b[j - (i + 1)];
+        // Check candidate pairs of bodies (from the broadphase grid) for collisions
+        for (i, j) in self.broadphase_pairs() {
+            let (body_a, body_b) = self.bodies.split_at_mut(i + 1);
+            let body_a = &mut body_a[i];
+            let body_b = &mut body_b[j - (i + 1)];
 
-                // Skip if both are static
-                if body_a.inv_mass == 0.0 && body_b.inv_mass == 0.0 {
-                    continue;
-                }
+            // Skip if both are static
+            if body_a.inv_mass == 0.0 && body_b.inv_mass == 0.0 {
+                continue;
+            }
+
+            if !body_a.should_collide(body_b) {
+                continue;
+            }
+
+            if !Self::one_way_contact_allowed(body_a, body_b) {
+                continue;
+            }
+
+            let midpoint = (body_a.position + body_b.position) * 0.5;
+            let friction = 0.4; // Friction coefficient
 
-                // Branch based on collision shape
-                match (body_a.shape, body_b.shape) {
-                    (CollisionShape::Sphere, CollisionShape::Sphere) => {
-                        // Sphere vs. Sphere collision
-                        if let Some((penetration, normal)) = compute_sphere_collision(body_a, body_b) {
-                            resolve_sphere_collision(body_a, body_b, normal, penetration, restitution, friction);
+            // Branch based on collision shape, recording a ContactConstraint for every
+            // shape pair (and for the static-mesh branch, resolving immediately since it
+            // doesn't participate in the iterative solver)
+            match (body_a.shape, body_b.shape) {
+                (CollisionShape::Sphere, CollisionShape::Sphere) => {
+                    // Sphere vs. Sphere collision
+                    if let Some((penetration, normal)) = sphere_vs_sphere(body_a, body_b) {
+                        self.push_contact_constraint(i, j, normal, penetration, &mut constraints);
+                        pending.push((i, j, midpoint));
+                    } else if self.continuous_detection {
+                        // Discrete test found no overlap at the end of the step; check
+                        // whether the bodies tunneled through each other during it
+                        let p = prev_positions[i] - prev_positions[j];
+                        let u = body_a.velocity - body_b.velocity;
+                        let combined_radius = body_a.radius + body_b.radius;
+                        if let Some(toi) = Self::sphere_sphere_toi(p, u, combined_radius, self.fixed_delta_time) {
+                            let back = (1.0 - toi) * self.fixed_delta_time;
+                            body_a.position -= body_a.velocity * back;
+                            body_b.position -= body_b.velocity * back;
+                            if let Some((penetration, normal)) = sphere_vs_sphere(body_a, body_b) {
+                                self.push_contact_constraint(i, j, normal, penetration, &mut constraints);
+                                pending.push((i, j, midpoint));
+                            }
                         }
                     }
-                    (CollisionShape::AABB, CollisionShape::AABB) => {
-                        // AABB vs. AABB collision
-                        let aabb_a = AABB::from_rigid_body(body_a);
-                        let aabb_b = AABB::from_rigid_body(body_b);
-                        if let Some((penetration, normal)) = compute_aabb_collision(&aabb_a, &aabb_b) {
-                            resolve_aabb_collision(body_a, body_b, normal, penetration, restitution, friction);
-                        }
+                }
+                (CollisionShape::AABB, CollisionShape::AABB) => {
+                    // AABB vs. AABB collision
+                    let aabb_a = AABB::from_rigid_body(body_a);
+                    let aabb_b = AABB::from_rigid_body(body_b);
+                    if let Some((penetration, normal)) = compute_aabb_collision(&aabb_a, &aabb_b) {
+                        self.push_contact_constraint(i, j, normal, penetration, &mut constraints);
+                        pending.push((i, j, midpoint));
                     }
-                    (CollisionShape::OBB, CollisionShape::OBB) => {
-                        // OBB vs. OBB collision
-                        let obb_a = OBB::from_rigid_body(body_a);
-                        let obb_b = OBB::from_rigid_body(body_b);
-                        if let Some((penetration, normal)) = compute_obb_collision(&obb_a, &obb_b) {
-                            resolve_obb_collision(body_a, body_b, normal, penetration, restitution, friction);
-                        }
+                }
+                (CollisionShape::OBB, CollisionShape::OBB) => {
+                    // OBB vs. OBB collision
+                    let obb_a = OBB::from_rigid_body(body_a);
+                    let obb_b = OBB::from_rigid_body(body_b);
+                    if let Some((penetration, normal, _)) = compute_obb_collision(&obb_a, &obb_b) {
+                        self.push_contact_constraint(i, j, normal, penetration, &mut constraints);
+                        pending.push((i, j, midpoint));
                     }
-                    (CollisionShape::OBB, CollisionShape::AABB) | (CollisionShape::AABB, CollisionShape::OBB) => {
-                        // Mixed: OBB vs. AABB collision
-                        let (obb, aabb) = if body_a.shape == CollisionShape::OBB {
-                            (OBB::from_rigid_body(body_a), AABB::from_rigid_body(body_b))
-                        } else {
-                            (OBB::from_rigid_body(body_b), AABB::from_rigid_body(body_a))
-                        };
-                        if let Some((penetration, normal)) = compute_obb_aabb_collision(&obb, &aabb) {
-                            resolve_obb_aabb_collision(body_a, body_b, normal, penetration, restitution, friction);
-                        }
+                }
+                (CollisionShape::OBB, CollisionShape::AABB) | (CollisionShape::AABB, CollisionShape::OBB) => {
+                    // Mixed: OBB vs. AABB collision
+                    let (obb, aabb) = if body_a.shape == CollisionShape::OBB {
+                        (OBB::from_rigid_body(body_a), AABB::from_rigid_body(body_b))
+                    } else {
+                        (OBB::from_rigid_body(body_b), AABB::from_rigid_body(body_a))
+                    };
+                    if let Some((penetration, normal)) = compute_obb_aabb_collision(&obb, &aabb) {
+                        self.push_contact_constraint(i, j, normal, penetration, &mut constraints);
+                        pending.push((i, j, midpoint));
+                    }
+                }
+                (CollisionShape::Sphere, CollisionShape::TriangleMesh)
+                | (CollisionShape::TriangleMesh, CollisionShape::Sphere) => {
+                    // Sphere vs. static triangle mesh collision; resolved immediately, since
+                    // the mesh side has no body index to key a persistent constraint on
+                    Self::resolve_sphere_mesh(body_a, body_b, restitution, friction);
+                }
+                (CollisionShape::ConvexHull, CollisionShape::ConvexHull) => {
+                    // Convex hull vs. convex hull collision (GJK/EPA)
+                    if let Some((penetration, normal)) = compute_convex_collision(body_a, body_b) {
+                        self.push_contact_constraint(i, j, normal, penetration, &mut constraints);
+                        pending.push((i, j, midpoint));
                     }
-                    _ => {} // Unhandled collision type
                 }
+                _ => {} // Unhandled collision type
             }
         }
+
+        solve_contact_constraints(&mut self.bodies, &mut constraints, restitution, self.solver_iterations, self.fixed_delta_time, self.contact_bias_factor);
+
+        self.contact_constraints.clear();
+        for (constraint, (i, j, midpoint)) in constraints.into_iter().zip(pending.into_iter()) {
+            self.contact_constraints.insert((i, j), (constraint.accumulated_normal_impulse, constraint.accumulated_tangent_impulse));
+            self.record_contact(i, j, midpoint, constraint.normal, constraint.penetration, constraint.accumulated_normal_impulse, &mut touched_this_step);
+        }
+
+        self.end_collision_events(&touched_this_step);
+    }
+
+    /// Whether a contact between `a` and `b` should be solved at all, given either
+    /// body's `one_way_normal`. A one-way body only resists approach from the side its
+    /// normal points toward; if the other body's velocity relative to it is heading out
+    /// along that normal (rising through from behind), the contact is skipped entirely
+    /// so it passes through instead of being stopped.
+    fn one_way_contact_allowed(a: &RigidBody, b: &RigidBody) -> bool {
+        let relative_velocity = b.velocity - a.velocity;
+        if let Some(normal) = a.one_way_normal {
+            if relative_velocity.dot(&normal) > 0.0 {
+                return false;
+            }
+        }
+        if let Some(normal) = b.one_way_normal {
+            if (Vector3::zero() - relative_velocity).dot(&normal) > 0.0 {
+                return false;
+            }
+        }
+        true
+    }
+
+    /// Builds a `ContactConstraint` for the pair `(i, j)`, warm-starting its
+    /// `accumulated_normal_impulse` from whatever `solve_contact_constraints` left behind
+    /// for this pair last step (0 the first time the pair touches).
+    fn push_contact_constraint(
+        &self,
+        i: usize,
+        j: usize,
+        normal: Vector3,
+        penetration: f32,
+        constraints: &mut Vec<ContactConstraint>,
+    ) {
+        let (warm_normal, warm_tangent) = self.contact_constraints.get(&(i, j)).copied().unwrap_or((0.0, [0.0, 0.0]));
+        constraints.push(ContactConstraint {
+            body_a: i,
+            body_b: j,
+            normal,
+            penetration,
+            accumulated_normal_impulse: warm_normal,
+            accumulated_tangent_impulse: warm_tangent,
+        });
     }
 
     /// Applies a uniform force (e.g. gravity) to all dynamic bodies
@@ -151,6 +805,183 @@ impl PhysicsWorld {
     pub fn constraints_mut(&mut self) -> &mut [Box<dyn Constraint>] {
         &mut self.constraints
     }
+
+    /// Serializes the scene (gravity, timestep, every body, and every constraint that
+    /// supports `Constraint::describe`) to a JSON string, using stable integer body
+    /// indices rather than raw pointers. Round-trips with `from_scene_json`.
+    ///
+    /// Bodies carrying `CollisionShape::TriangleMesh` round-trip their shape tag but
+    /// not their triangle geometry; reattach meshes with `RigidBody::set_mesh` after
+    /// loading. The same applies to `CollisionShape::ConvexHull`: reattach vertex
+    /// data with `RigidBody::set_convex_hull`.
+    pub fn to_scene_json(&self) -> String {
+        let bodies: Vec<JsonValue> = self.bodies.iter().map(|b| Self::body_to_json(b)).collect();
+        let constraints: Vec<JsonValue> = self.constraints.iter()
+            .filter_map(|c| c.describe(&self.bodies))
+            .map(Self::constraint_to_json)
+            .collect();
+
+        JsonValue::Object(vec![
+            ("gravity".to_string(), Self::vector3_to_json(self.gravity)),
+            ("fixed_delta_time".to_string(), JsonValue::Number(self.fixed_delta_time as f64)),
+            ("substep_count".to_string(), JsonValue::Number(self.substep_count as f64)),
+            ("bodies".to_string(), JsonValue::Array(bodies)),
+            ("constraints".to_string(), JsonValue::Array(constraints)),
+        ]).to_string()
+    }
+
+    /// Rebuilds a `PhysicsWorld` from a JSON string produced by `to_scene_json`.
+    /// Returns a description of the problem if the document is malformed or missing
+    /// a required field.
+    pub fn from_scene_json(json: &str) -> Result<Self, String> {
+        let root = crate::scene::parse(json)?;
+
+        let mut world = Self::new();
+        world.gravity = root.get("gravity").and_then(Self::json_to_vector3).unwrap_or(world.gravity);
+        world.fixed_delta_time = root.get("fixed_delta_time").and_then(|v| v.as_f32()).unwrap_or(world.fixed_delta_time);
+        world.substep_count = root.get("substep_count").and_then(|v| v.as_u32()).unwrap_or(world.substep_count);
+
+        let bodies = root.get("bodies").and_then(|v| v.as_array()).ok_or("scene is missing a \"bodies\" array")?;
+        for body_json in bodies {
+            world.bodies.push(Box::new(Self::body_from_json(body_json)?));
+        }
+
+        let constraints = root.get("constraints").and_then(|v| v.as_array()).unwrap_or(&[]);
+        for constraint_json in constraints {
+            world.constraints.push(Self::constraint_from_json(constraint_json, &mut world.bodies)?);
+        }
+
+        Ok(world)
+    }
+
+    fn vector3_to_json(v: Vector3) -> JsonValue {
+        JsonValue::Object(vec![
+            ("x".to_string(), JsonValue::Number(v.x as f64)),
+            ("y".to_string(), JsonValue::Number(v.y as f64)),
+            ("z".to_string(), JsonValue::Number(v.z as f64)),
+        ])
+    }
+
+    fn json_to_vector3(value: &JsonValue) -> Option<Vector3> {
+        Some(Vector3::new(
+            value.get("x")?.as_f32()?,
+            value.get("y")?.as_f32()?,
+            value.get("z")?.as_f32()?,
+        ))
+    }
+
+    fn quaternion_to_json(q: crate::math_utils::Quaternion) -> JsonValue {
+        JsonValue::Object(vec![
+            ("w".to_string(), JsonValue::Number(q.w as f64)),
+            ("x".to_string(), JsonValue::Number(q.x as f64)),
+            ("y".to_string(), JsonValue::Number(q.y as f64)),
+            ("z".to_string(), JsonValue::Number(q.z as f64)),
+        ])
+    }
+
+    fn json_to_quaternion(value: &JsonValue) -> Option<crate::math_utils::Quaternion> {
+        Some(crate::math_utils::Quaternion::new(
+            value.get("w")?.as_f32()?,
+            value.get("x")?.as_f32()?,
+            value.get("y")?.as_f32()?,
+            value.get("z")?.as_f32()?,
+        ))
+    }
+
+    fn shape_to_str(shape: CollisionShape) -> &'static str {
+        match shape {
+            CollisionShape::Sphere => "sphere",
+            CollisionShape::AABB => "aabb",
+            CollisionShape::OBB => "obb",
+            CollisionShape::TriangleMesh => "triangle_mesh",
+            CollisionShape::ConvexHull => "convex_hull",
+        }
+    }
+
+    fn shape_from_str(name: &str) -> Option<CollisionShape> {
+        match name {
+            "sphere" => Some(CollisionShape::Sphere),
+            "aabb" => Some(CollisionShape::AABB),
+            "obb" => Some(CollisionShape::OBB),
+            "triangle_mesh" => Some(CollisionShape::TriangleMesh),
+            "convex_hull" => Some(CollisionShape::ConvexHull),
+            _ => None,
+        }
+    }
+
+    fn body_to_json(body: &RigidBody) -> JsonValue {
+        JsonValue::Object(vec![
+            ("shape".to_string(), JsonValue::String(Self::shape_to_str(body.shape).to_string())),
+            ("mass".to_string(), JsonValue::Number(body.mass as f64)),
+            ("radius".to_string(), JsonValue::Number(body.radius as f64)),
+            ("half_extents".to_string(), Self::vector3_to_json(body.half_extents)),
+            ("position".to_string(), Self::vector3_to_json(body.position)),
+            ("rotation".to_string(), Self::quaternion_to_json(body.rotation)),
+            ("velocity".to_string(), Self::vector3_to_json(body.velocity)),
+            ("angular_velocity".to_string(), Self::vector3_to_json(body.angular_velocity)),
+            ("restitution".to_string(), JsonValue::Number(body.restitution as f64)),
+            ("friction".to_string(), JsonValue::Number(body.friction as f64)),
+        ])
+    }
+
+    fn body_from_json(value: &JsonValue) -> Result<RigidBody, String> {
+        let mut body = RigidBody::new();
+        body.shape = value.get("shape").and_then(|v| v.as_str()).and_then(Self::shape_from_str)
+            .ok_or("body is missing a valid \"shape\"")?;
+        body.set_mass(value.get("mass").and_then(|v| v.as_f32()).unwrap_or(0.0));
+        body.radius = value.get("radius").and_then(|v| v.as_f32()).unwrap_or(body.radius);
+        if let Some(half_extents) = value.get("half_extents").and_then(Self::json_to_vector3) {
+            body.half_extents = half_extents;
+        }
+        if let Some(position) = value.get("position").and_then(Self::json_to_vector3) {
+            body.position = position;
+        }
+        if let Some(rotation) = value.get("rotation").and_then(Self::json_to_quaternion) {
+            body.rotation = rotation;
+        }
+        if let Some(velocity) = value.get("velocity").and_then(Self::json_to_vector3) {
+            body.velocity = velocity;
+        }
+        if let Some(angular_velocity) = value.get("angular_velocity").and_then(Self::json_to_vector3) {
+            body.angular_velocity = angular_velocity;
+        }
+        body.restitution = value.get("restitution").and_then(|v| v.as_f32()).unwrap_or(body.restitution);
+        body.friction = value.get("friction").and_then(|v| v.as_f32()).unwrap_or(body.friction);
+        Ok(body)
+    }
+
+    fn constraint_to_json(descriptor: ConstraintDescriptor) -> JsonValue {
+        match descriptor {
+            ConstraintDescriptor::PointToPoint { body_a, body_b, pivot_a, pivot_b } => {
+                JsonValue::Object(vec![
+                    ("type".to_string(), JsonValue::String("point_to_point".to_string())),
+                    ("body_a".to_string(), JsonValue::Number(body_a as f64)),
+                    ("body_b".to_string(), JsonValue::Number(body_b as f64)),
+                    ("pivot_a".to_string(), Self::vector3_to_json(pivot_a)),
+                    ("pivot_b".to_string(), Self::vector3_to_json(pivot_b)),
+                ])
+            }
+        }
+    }
+
+    fn constraint_from_json(value: &JsonValue, bodies: &mut [Box<RigidBody>]) -> Result<Box<dyn Constraint>, String> {
+        let kind = value.get("type").and_then(|v| v.as_str()).ok_or("constraint is missing a \"type\"")?;
+        match kind {
+            "point_to_point" => {
+                let body_a = value.get("body_a").and_then(|v| v.as_u32()).ok_or("constraint is missing \"body_a\"")? as usize;
+                let body_b = value.get("body_b").and_then(|v| v.as_u32()).ok_or("constraint is missing \"body_b\"")? as usize;
+                let pivot_a = value.get("pivot_a").and_then(Self::json_to_vector3).unwrap_or(Vector3::zero());
+                let pivot_b = value.get("pivot_b").and_then(Self::json_to_vector3).unwrap_or(Vector3::zero());
+                if body_a >= bodies.len() || body_b >= bodies.len() {
+                    return Err(format!("constraint references out-of-range body index ({}, {})", body_a, body_b));
+                }
+                let ptr_a = bodies[body_a].as_mut() as *mut RigidBody;
+                let ptr_b = bodies[body_b].as_mut() as *mut RigidBody;
+                Ok(Box::new(crate::constraints::PointToPointConstraint::new(ptr_a, ptr_b, pivot_a, pivot_b)))
+            }
+            other => Err(format!("unknown constraint type \"{}\"", other)),
+        }
+    }
 }
 
 impl Default for PhysicsWorld {
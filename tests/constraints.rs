@@ -0,0 +1,251 @@
+use physics_engine::aabb::{RigidBody, CollisionShape, CollisionGroups};
+use physics_engine::world::PhysicsWorld;
+use physics_engine::math_utils::{Vector3, Matrix3};
+use physics_engine::constraints::{
+    PointToPointConstraint, HingeConstraint, SliderConstraint, ConeTwistConstraint,
+    Generic6DOFConstraint, UniversalJoint, DofLimit,
+};
+
+// These tests exercise constraint solving in isolation, so bodies are put in their own
+// collision group: two spheres pinned a fixed distance apart would otherwise register as
+// a contact whenever a joint's limit or motor pushes them to touching distance, and the
+// contact solver resolving that contact would mask what the constraint itself is doing.
+fn dynamic_body(position: Vector3, mass: f32) -> RigidBody {
+    let mut body = RigidBody::new();
+    body.shape = CollisionShape::Sphere;
+    body.set_mass(mass);
+    body.set_radius(0.5);
+    body.recompute_inertia();
+    body.position = position;
+    body.collision_groups = CollisionGroups::none();
+    body
+}
+
+#[test]
+fn test_point_to_point_warm_started_bias_converges() {
+    // Two bodies pinned together at a point, started with a gap between the pivots.
+    // The full-Jacobian solve (chunk3-1), warm-started accumulated impulse (chunk3-2),
+    // and Baumgarte bias (chunk3-3) together should close the gap over a handful of
+    // steps rather than leaving it permanently unresolved or oscillating forever.
+    let mut world = PhysicsWorld::new();
+    world.set_gravity(Vector3::zero());
+    world.set_fixed_delta_time(1.0 / 60.0);
+
+    world.add_body(dynamic_body(Vector3::new(-1.0, 0.0, 0.0), 1.0));
+    world.add_body(dynamic_body(Vector3::new(1.0, 0.0, 0.0), 1.0));
+
+    let bodies = world.bodies_mut();
+    let a: *mut RigidBody = bodies[0].as_mut();
+    let b: *mut RigidBody = bodies[1].as_mut();
+
+    let constraint = PointToPointConstraint::new(a, b, Vector3::zero(), Vector3::zero());
+    world.add_constraint(Box::new(constraint));
+
+    for _ in 0..240 {
+        world.step();
+    }
+
+    let bodies = world.bodies();
+    let pivot_a = bodies[0].position;
+    let pivot_b = bodies[1].position;
+    let gap = (pivot_b - pivot_a).length();
+    assert!(gap < 0.05, "pivots never converged, gap = {}", gap);
+}
+
+#[test]
+fn test_hinge_motor_drives_relative_angular_velocity() {
+    // chunk3-4: a hinge motor should accelerate the relative spin about the hinge
+    // axis toward its target velocity, independent of any pivot/axis constraint.
+    let mut world = PhysicsWorld::new();
+    world.set_gravity(Vector3::zero());
+    world.set_fixed_delta_time(1.0 / 60.0);
+
+    let mut a = dynamic_body(Vector3::new(-1.0, 0.0, 0.0), 1.0);
+    a.shape = CollisionShape::AABB;
+    a.set_half_extents(Vector3::new(0.5, 0.5, 0.5));
+    a.recompute_inertia();
+    let mut b = dynamic_body(Vector3::new(1.0, 0.0, 0.0), 1.0);
+    b.shape = CollisionShape::AABB;
+    b.set_half_extents(Vector3::new(0.5, 0.5, 0.5));
+    b.recompute_inertia();
+
+    world.add_body(a);
+    world.add_body(b);
+
+    let bodies = world.bodies_mut();
+    let pa: *mut RigidBody = bodies[0].as_mut();
+    let pb: *mut RigidBody = bodies[1].as_mut();
+
+    let mut hinge = HingeConstraint::new(
+        pa, pb,
+        Vector3::new(1.0, 0.0, 0.0), Vector3::new(-1.0, 0.0, 0.0),
+        Vector3::new(0.0, 0.0, 1.0), Vector3::new(0.0, 0.0, 1.0),
+    );
+    hinge.set_motor(4.0, 100.0);
+    world.add_constraint(Box::new(hinge));
+
+    for _ in 0..120 {
+        world.step();
+    }
+
+    let bodies = world.bodies();
+    let relative_spin = (bodies[1].angular_velocity - bodies[0].angular_velocity).dot(&Vector3::new(0.0, 0.0, 1.0));
+    assert!(relative_spin > 2.0, "motor failed to spin up the hinge, relative_spin = {}", relative_spin);
+}
+
+#[test]
+fn test_slider_motor_drives_linear_velocity_and_respects_limits() {
+    // chunk3-8: a slider motor should push the relative velocity along the slider
+    // axis toward its target, and set_limits should cap how far it can travel.
+    let mut world = PhysicsWorld::new();
+    world.set_gravity(Vector3::zero());
+    world.set_fixed_delta_time(1.0 / 60.0);
+
+    world.add_body(dynamic_body(Vector3::new(0.0, 0.0, 0.0), 1.0));
+    world.add_body(dynamic_body(Vector3::new(1.0, 0.0, 0.0), 1.0));
+
+    let bodies = world.bodies_mut();
+    let a: *mut RigidBody = bodies[0].as_mut();
+    let b: *mut RigidBody = bodies[1].as_mut();
+
+    let mut slider = SliderConstraint::new(
+        a, b, Vector3::zero(), Vector3::zero(),
+        Vector3::new(1.0, 0.0, 0.0), Vector3::new(1.0, 0.0, 0.0),
+    );
+    slider.set_motor(2.0, 50.0);
+    slider.set_limits(0.5, 3.0);
+    world.add_constraint(Box::new(slider));
+
+    for _ in 0..300 {
+        world.step();
+    }
+
+    let bodies = world.bodies();
+    let travel = (bodies[1].position - bodies[0].position).x;
+    assert!(travel <= 3.0 + 0.1, "slider exceeded its upper limit, travel = {}", travel);
+    assert!(travel >= 0.5 - 0.01, "slider collapsed below its lower limit, travel = {}", travel);
+}
+
+#[test]
+fn test_cone_twist_constrains_swing_within_span() {
+    // chunk3-7: with a tight swing cone, a large initial angular velocity about an
+    // axis perpendicular to the cone's axis should be reined in rather than letting
+    // body B swing freely away from body A's reference axis.
+    let mut world = PhysicsWorld::new();
+    world.set_gravity(Vector3::zero());
+    world.set_fixed_delta_time(1.0 / 60.0);
+
+    let mut a = dynamic_body(Vector3::new(0.0, 0.0, 0.0), 0.0); // static anchor
+    a.shape = CollisionShape::Sphere;
+    let mut b = dynamic_body(Vector3::new(1.0, 0.0, 0.0), 1.0);
+    b.shape = CollisionShape::Sphere;
+    b.recompute_inertia();
+    b.angular_velocity = Vector3::new(0.0, 0.0, 5.0);
+
+    world.add_body(a);
+    world.add_body(b);
+
+    let bodies = world.bodies_mut();
+    let pa: *mut RigidBody = bodies[0].as_mut();
+    let pb: *mut RigidBody = bodies[1].as_mut();
+
+    // Pivots must describe the same world anchor (here, body A's fixed position) or the
+    // point-to-point half of the constraint injects a large spurious correction that
+    // swamps the swing dynamics this test is actually exercising.
+    let mut cone = ConeTwistConstraint::new(
+        pa, pb, Vector3::zero(), Vector3::new(-1.0, 0.0, 0.0),
+        Vector3::new(1.0, 0.0, 0.0), Vector3::new(1.0, 0.0, 0.0),
+    );
+    cone.set_swing_span1(0.2);
+    cone.set_swing_span2(0.2);
+    cone.set_twist_span(0.2);
+    world.add_constraint(Box::new(cone));
+
+    for _ in 0..180 {
+        world.step();
+    }
+
+    let bodies = world.bodies();
+    let axis_a = Vector3::new(1.0, 0.0, 0.0);
+    let axis_b = bodies[1].rotation.to_matrix() * Vector3::new(1.0, 0.0, 0.0);
+    let swing = axis_a.dot(&axis_b).clamp(-1.0, 1.0).acos();
+    assert!(swing < 0.35, "swing cone failed to hold, swing angle = {}", swing);
+}
+
+#[test]
+fn test_universal_joint_limits_spin_about_axis1() {
+    // chunk3-6: a fast spin about axis1 should be slowed by set_limit1 rather than
+    // carrying on freely.
+    let mut world = PhysicsWorld::new();
+    world.set_gravity(Vector3::zero());
+    world.set_fixed_delta_time(1.0 / 60.0);
+
+    let a = dynamic_body(Vector3::new(0.0, 0.0, 0.0), 0.0); // static anchor
+    let mut b = dynamic_body(Vector3::new(1.0, 0.0, 0.0), 1.0);
+    b.shape = CollisionShape::Sphere;
+    b.recompute_inertia();
+    b.angular_velocity = Vector3::new(0.0, 0.0, 10.0); // spin about axis1 (z)
+
+    world.add_body(a);
+    world.add_body(b);
+
+    let bodies = world.bodies_mut();
+    let pa: *mut RigidBody = bodies[0].as_mut();
+    let pb: *mut RigidBody = bodies[1].as_mut();
+
+    let mut joint = unsafe { UniversalJoint::new(pa, pb, Vector3::new(0.0, 0.0, 0.0), Vector3::new(0.0, 0.0, 1.0), Vector3::new(0.0, 1.0, 0.0)) };
+    joint.set_limit1(-0.3, 0.3);
+    world.add_constraint(Box::new(joint));
+
+    for _ in 0..180 {
+        world.step();
+    }
+
+    // The joint is still registered as the world's only constraint.
+    let constraints = world.constraints();
+    assert_eq!(constraints.len(), 1);
+
+    let bodies = world.bodies();
+    assert!(bodies[1].angular_velocity.z.abs() < 10.0, "axis1 spin was not slowed by its limit");
+}
+
+#[test]
+fn test_generic_6dof_locks_all_axes_like_a_weld() {
+    // chunk3-5: locking every linear and angular DOF should behave like a rigid
+    // weld, holding body B's position and orientation relative to body A fixed
+    // even when it starts with some relative velocity.
+    let mut world = PhysicsWorld::new();
+    world.set_gravity(Vector3::zero());
+    world.set_fixed_delta_time(1.0 / 60.0);
+
+    let a = dynamic_body(Vector3::new(0.0, 0.0, 0.0), 0.0); // static anchor
+    let mut b = dynamic_body(Vector3::new(1.0, 0.0, 0.0), 1.0);
+    b.shape = CollisionShape::Sphere;
+    b.recompute_inertia();
+    b.velocity = Vector3::new(0.0, 2.0, 0.0);
+
+    world.add_body(a);
+    world.add_body(b);
+
+    let bodies = world.bodies_mut();
+    let pa: *mut RigidBody = bodies[0].as_mut();
+    let pb: *mut RigidBody = bodies[1].as_mut();
+
+    // Pivots must describe the *same* world anchor at construction time (here, body B's
+    // starting position) or locking to 0 immediately yanks the bodies to make them agree.
+    let mut dof6 = Generic6DOFConstraint::new(pa, pb, Matrix3::identity(), Matrix3::identity(), Vector3::new(1.0, 0.0, 0.0), Vector3::zero());
+    for axis in 0..3 {
+        dof6.set_linear_limit(axis, DofLimit::locked(0.0));
+        dof6.set_angular_limit(axis, DofLimit::locked(0.0));
+    }
+    world.add_constraint(Box::new(dof6));
+
+    let start = world.bodies()[1].position;
+    for _ in 0..120 {
+        world.step();
+    }
+
+    let end = world.bodies()[1].position;
+    let drift = (end - start).length();
+    assert!(drift < 0.2, "welded body drifted away from its anchor, drift = {}", drift);
+}
@@ -0,0 +1,113 @@
+use physics_engine::aabb::{RigidBody, CollisionShape};
+use physics_engine::math_utils::{Vector3, Matrix3, Quaternion};
+use physics_engine::collision::{OBB, compute_obb_collision, generate_obb_manifold, compute_obb_toi, collision, SatAxis};
+
+fn dynamic_obb(position: Vector3, half_extents: Vector3, mass: f32) -> RigidBody {
+    let mut body = RigidBody::new();
+    body.shape = CollisionShape::OBB;
+    body.set_mass(mass);
+    body.set_half_extents(half_extents);
+    body.recompute_inertia();
+    body.position = position;
+    body
+}
+
+#[test]
+fn test_solve_manifold_pair_imparts_spin_from_off_center_impact() {
+    // chunk7-1: a manifold point applied away from the body's center of mass should
+    // pick up angular velocity through the lever arm, not just a straight-line bounce,
+    // since `solve_manifold_pair` (unlike the center-of-mass-only contact solver) solves
+    // each point at its own world position using the inertia tensor.
+    let mut a = dynamic_obb(Vector3::new(0.0, 0.0, 0.0), Vector3::new(0.5, 0.5, 0.5), 0.0); // static anchor
+    let mut b = dynamic_obb(Vector3::new(0.9, 0.0, 0.0), Vector3::new(0.5, 0.5, 0.5), 1.0);
+    b.velocity = Vector3::new(-2.0, 0.0, 0.0);
+
+    // Strike near one corner of the contact face (off the x-axis through the centers)
+    // so the impulse has a lever arm and should spin body b about z.
+    let mut manifold = physics_engine::collision::ContactManifold {
+        normal: Vector3::new(1.0, 0.0, 0.0), // points from a to b
+        points: vec![physics_engine::collision::ContactPoint {
+            position: Vector3::new(0.4, 0.4, 0.0),
+            penetration: 0.1,
+            normal_impulse: 0.0,
+        }],
+    };
+
+    assert_eq!(b.angular_velocity.length(), 0.0);
+    collision::solve_manifold_pair(&mut a, &mut b, &mut manifold, 0.0, 8);
+
+    assert!(b.angular_velocity.z.abs() > 0.01, "off-center impact failed to impart spin, angvel = {:?}", b.angular_velocity);
+    assert!(b.velocity.x > -2.0, "normal impulse failed to slow body b's approach, vel.x = {}", b.velocity.x);
+}
+
+#[test]
+fn test_generate_obb_manifold_produces_multiple_contact_points() {
+    // chunk7-2: a box resting flat on another box's top face should clip to a
+    // multi-point manifold (one per overlapping corner of the face), not collapse
+    // to a single averaged contact point -- that's what lets a resting box resist
+    // toppling instead of balancing on a single pivot.
+    let a = OBB { position: Vector3::new(0.0, 0.0, 0.0), half_extents: Vector3::new(1.0, 0.5, 1.0), rotation: Matrix3::identity() };
+    let b = OBB { position: Vector3::new(0.0, 0.95, 0.0), half_extents: Vector3::new(1.0, 0.5, 1.0), rotation: Matrix3::identity() };
+
+    let manifold = generate_obb_manifold(&a, &b).expect("overlapping face-aligned boxes should produce a manifold");
+    assert!(manifold.points.len() >= 3, "face-face overlap should clip to multiple contact points, got {}", manifold.points.len());
+    assert!((manifold.normal.y - 1.0).abs() < 1e-3, "manifold normal should point straight up from a to b, normal = {:?}", manifold.normal);
+    for point in &manifold.points {
+        assert!(point.penetration > 0.0, "every clipped point should report positive penetration");
+    }
+}
+
+#[test]
+fn test_compute_obb_toi_detects_fast_moving_pair() {
+    // chunk7-3: two boxes far enough apart to not be overlapping right now, but closing
+    // fast enough to tunnel through each other within one timestep, should still report
+    // a time of impact inside [0, dt] -- this is exactly the case continuous detection
+    // exists to catch, since a purely discrete end-of-step test would miss it.
+    let a = OBB { position: Vector3::new(-5.0, 0.0, 0.0), half_extents: Vector3::new(0.5, 0.5, 0.5), rotation: Matrix3::identity() };
+    let b = OBB { position: Vector3::new(5.0, 0.0, 0.0), half_extents: Vector3::new(0.5, 0.5, 0.5), rotation: Matrix3::identity() };
+    let vel_a = Vector3::new(600.0, 0.0, 0.0);
+    let vel_b = Vector3::zero();
+    let dt = 1.0 / 60.0;
+
+    let toi = compute_obb_toi(&a, &vel_a, &b, &vel_b, dt).expect("fast-closing boxes should report a time of impact");
+    assert!((0.0..=dt).contains(&toi), "time of impact {} should fall within this step's [0, dt]", toi);
+
+    // A pair moving apart (or too slow to ever meet) should report no impact at all.
+    let vel_a_away = Vector3::new(-600.0, 0.0, 0.0);
+    assert!(compute_obb_toi(&a, &vel_a_away, &b, &vel_b, dt).is_none(), "separating boxes should not report a time of impact");
+}
+
+#[test]
+fn test_obb_manifold_face_b_reference() {
+    // A rotated, asymmetric pair of OBBs picked so that compute_obb_collision's SAT
+    // test picks one of B's faces (not A's) as the reference axis. generate_obb_manifold
+    // must flip the reference-face sign convention for a B-reference the same way it does
+    // for edge-edge and A-reference cases, or the clipped contact points come out deep
+    // inside the wrong side of B's face instead of near the true penetration depth.
+    let a = OBB {
+        position: Vector3::new(0.0, 0.0, 0.0),
+        half_extents: Vector3::new(0.953, 0.656, 0.772),
+        rotation: Quaternion::from_axis_angle(Vector3::new(-0.419, -0.713, -0.188), 0.253).to_matrix(),
+    };
+    let b = OBB {
+        position: Vector3::new(-0.153, -0.721, -0.631),
+        half_extents: Vector3::new(0.519, 0.765, 0.348),
+        rotation: Quaternion::from_axis_angle(Vector3::new(-0.075, -0.679, -0.964), 0.252).to_matrix(),
+    };
+
+    let (penetration, _normal, axis) = compute_obb_collision(&a, &b).expect("boxes should overlap");
+    assert!(matches!(axis, SatAxis::FaceB(_)), "test fixture should exercise the FaceB branch, got {:?}", axis);
+
+    let manifold = generate_obb_manifold(&a, &b).expect("overlapping boxes should produce a manifold");
+    assert!(!manifold.points.is_empty());
+    for point in &manifold.points {
+        // Each clipped point's penetration should track the SAT-reported depth; the old,
+        // wrong-sign formula clipped against the far side of B's face instead of the near
+        // side and reported penetrations more than 3x too deep.
+        assert!(
+            point.penetration > 0.0 && point.penetration < penetration + 0.5,
+            "penetration {} too far from SAT depth {}",
+            point.penetration, penetration,
+        );
+    }
+}
@@ -0,0 +1,218 @@
+use physics_engine::aabb::{RigidBody, CollisionShape, CollisionGroups};
+use physics_engine::world::PhysicsWorld;
+use physics_engine::math_utils::Vector3;
+
+fn dynamic_aabb(position: Vector3, mass: f32) -> RigidBody {
+    let mut body = RigidBody::new();
+    body.shape = CollisionShape::AABB;
+    body.set_mass(mass);
+    body.set_half_extents(Vector3::new(0.5, 0.5, 0.5));
+    body.recompute_inertia();
+    body.position = position;
+    body
+}
+
+#[test]
+fn test_damping_bleeds_off_velocity() {
+    // chunk6-6: a body with no forces acting on it should still lose speed every
+    // step once linear_damping/angular_damping are set, rather than coasting forever.
+    let mut world = PhysicsWorld::new();
+    world.set_gravity(Vector3::zero());
+    world.set_fixed_delta_time(1.0 / 60.0);
+
+    let mut body = dynamic_aabb(Vector3::zero(), 1.0);
+    body.velocity = Vector3::new(10.0, 0.0, 0.0);
+    body.angular_velocity = Vector3::new(0.0, 0.0, 10.0);
+    body.linear_damping = 2.0;
+    body.angular_damping = 2.0;
+    body.collision_groups = CollisionGroups::none();
+    world.add_body(body);
+
+    for _ in 0..60 {
+        world.step();
+    }
+
+    let bodies = world.bodies();
+    assert!(bodies[0].velocity.x < 5.0, "linear velocity wasn't damped, vx = {}", bodies[0].velocity.x);
+    assert!(bodies[0].angular_velocity.z < 5.0, "angular velocity wasn't damped, wz = {}", bodies[0].angular_velocity.z);
+}
+
+#[test]
+fn test_collision_groups_filter_out_contact() {
+    // chunk6-4: two overlapping, approaching bodies in non-colliding groups should
+    // pass through each other instead of generating a contact.
+    let mut world = PhysicsWorld::new();
+    world.set_gravity(Vector3::zero());
+    world.set_fixed_delta_time(1.0 / 60.0);
+
+    let mut a = dynamic_aabb(Vector3::new(-0.6, 0.0, 0.0), 1.0);
+    a.velocity = Vector3::new(5.0, 0.0, 0.0);
+    a.collision_groups = CollisionGroups::new(1 << 0, 1 << 0); // only collides with layer 0
+
+    let mut b = dynamic_aabb(Vector3::new(0.6, 0.0, 0.0), 1.0);
+    b.velocity = Vector3::new(-5.0, 0.0, 0.0);
+    b.collision_groups = CollisionGroups::new(1 << 1, 1 << 1); // layer 1, never overlaps layer 0
+
+    world.add_body(a);
+    world.add_body(b);
+
+    for _ in 0..60 {
+        world.step();
+    }
+
+    let bodies = world.bodies();
+    // With filtering disabled these bodies would have been stopped dead around x=0;
+    // since their groups never intersect, they should have sailed straight through.
+    assert!(bodies[0].position.x > 1.0, "bodies that shouldn't collide were stopped, a.x = {}", bodies[0].position.x);
+    assert!(bodies[1].position.x < -1.0, "bodies that shouldn't collide were stopped, b.x = {}", bodies[1].position.x);
+}
+
+#[test]
+fn test_one_way_platform_lets_body_pass_from_below() {
+    // chunk6-5: a body rising into a one-way platform from the non-resisting side
+    // should pass through untouched, while the same platform still stops a body
+    // falling onto it from above.
+    let mut rising = PhysicsWorld::new();
+    rising.set_gravity(Vector3::zero());
+    rising.set_fixed_delta_time(1.0 / 60.0);
+
+    let mut platform = dynamic_aabb(Vector3::new(0.0, 0.0, 0.0), 0.0);
+    platform.one_way_normal = Some(Vector3::new(0.0, 1.0, 0.0));
+    let mut rising_body = dynamic_aabb(Vector3::new(0.0, -0.9, 0.0), 1.0);
+    rising_body.velocity = Vector3::new(0.0, 5.0, 0.0);
+
+    rising.add_body(platform);
+    rising.add_body(rising_body);
+    rising.step();
+
+    assert!(rising.bodies()[1].velocity.y > 0.0, "body moving up through a one-way platform was stopped");
+
+    let mut falling = PhysicsWorld::new();
+    falling.set_gravity(Vector3::zero());
+    falling.set_fixed_delta_time(1.0 / 60.0);
+
+    let mut platform = dynamic_aabb(Vector3::new(0.0, 0.0, 0.0), 0.0);
+    platform.one_way_normal = Some(Vector3::new(0.0, 1.0, 0.0));
+    let mut falling_body = dynamic_aabb(Vector3::new(0.0, 0.9, 0.0), 1.0);
+    falling_body.velocity = Vector3::new(0.0, -5.0, 0.0);
+
+    falling.add_body(platform);
+    falling.add_body(falling_body);
+    falling.step();
+
+    assert!(falling.bodies()[1].velocity.y > -5.0, "body landing on a one-way platform from above wasn't stopped");
+}
+
+#[test]
+fn test_collision_events_enter_stay_then_exit() {
+    // chunk6-3: the first step two bodies touch should report Enter, subsequent
+    // touching steps Stay, and the step they separate should report Exit.
+    use physics_engine::collision::ContactPhase;
+
+    let mut world = PhysicsWorld::new();
+    world.set_gravity(Vector3::zero());
+    world.set_fixed_delta_time(1.0 / 60.0);
+
+    let a = dynamic_aabb(Vector3::new(-0.45, 0.0, 0.0), 1.0);
+    let b = dynamic_aabb(Vector3::new(0.45, 0.0, 0.0), 1.0);
+    world.add_body(a);
+    world.add_body(b);
+
+    world.step();
+    let events = world.drain_collision_events();
+    assert!(events.iter().any(|e| e.phase == ContactPhase::Enter), "first touching step should report Enter");
+
+    world.step();
+    let events = world.drain_collision_events();
+    assert!(events.iter().any(|e| e.phase == ContactPhase::Stay), "second touching step should report Stay");
+
+    // Pull them far enough apart that the next step no longer finds a contact.
+    {
+        let bodies = world.bodies_mut();
+        bodies[0].position.x = -10.0;
+        bodies[1].position.x = 10.0;
+    }
+    world.step();
+    let events = world.drain_collision_events();
+    assert!(events.iter().any(|e| e.phase == ContactPhase::Exit), "separating step should report Exit");
+}
+
+#[test]
+fn test_broadphase_large_scene_detects_sparse_collision() {
+    // chunk6-2: once the body count crosses the all-pairs/grid threshold, the spatial
+    // grid broad phase takes over; it must still find a colliding pair even when
+    // most of the scene is far away and in other cells.
+    let mut world = PhysicsWorld::new();
+    world.set_gravity(Vector3::zero());
+    world.set_fixed_delta_time(1.0 / 60.0);
+    world.set_broadphase_cell_size(2.0);
+
+    // Scatter enough far-apart static bodies to push body count past the
+    // all-pairs fallback threshold, each isolated in its own grid cell.
+    for i in 0..40 {
+        world.add_body(dynamic_aabb(Vector3::new(i as f32 * 20.0, 100.0, 0.0), 0.0));
+    }
+
+    // One real overlapping pair, off in its own corner of the world.
+    let mut a = dynamic_aabb(Vector3::new(-0.55, -500.0, 0.0), 1.0);
+    a.velocity = Vector3::new(5.0, 0.0, 0.0);
+    let mut b = dynamic_aabb(Vector3::new(0.55, -500.0, 0.0), 1.0);
+    b.velocity = Vector3::new(-5.0, 0.0, 0.0);
+    world.add_body(a);
+    world.add_body(b);
+
+    world.step();
+
+    let bodies = world.bodies();
+    let n = bodies.len();
+    assert!(bodies[n - 2].velocity.x.abs() < 5.0, "the grid broad phase missed a real contact, va = {:?}", bodies[n - 2].velocity);
+    assert!(bodies[n - 1].velocity.x.abs() < 5.0, "the grid broad phase missed a real contact, vb = {:?}", bodies[n - 1].velocity);
+}
+
+#[test]
+fn test_stack_of_boxes_settles_without_sinking() {
+    // chunk2-4/chunk4-4/chunk6-1/chunk7-4: a small stack of boxes resting on a static
+    // floor should settle near its resting height under gravity rather than sinking
+    // through it or jittering forever — the signature of a working warm-started,
+    // multi-iteration sequential-impulse contact solver.
+    let mut world = PhysicsWorld::new();
+    world.set_fixed_delta_time(1.0 / 60.0);
+    world.set_solver_iterations(10);
+
+    let floor = dynamic_aabb(Vector3::new(0.0, -0.5, 0.0), 0.0);
+    world.add_body(floor);
+    world.add_body(dynamic_aabb(Vector3::new(0.0, 0.55, 0.0), 1.0));
+    world.add_body(dynamic_aabb(Vector3::new(0.0, 1.65, 0.0), 1.0));
+
+    for _ in 0..240 {
+        world.step();
+    }
+
+    let bodies = world.bodies();
+    assert!(bodies[1].position.y > 0.3, "bottom box sank through the floor, y = {}", bodies[1].position.y);
+    assert!(bodies[2].position.y > bodies[1].position.y + 0.5, "top box sank into the bottom box, gap = {}", bodies[2].position.y - bodies[1].position.y);
+    assert!(bodies[1].velocity.length() < 0.5, "stack never settled, bottom box still moving at {:?}", bodies[1].velocity);
+}
+
+#[test]
+fn test_advance_runs_fixed_steps_from_variable_real_dt() {
+    // chunk6-8: advance(real_dt) should accumulate elapsed time and run as many
+    // fixed_delta_time steps as fit, rather than integrating by the variable real_dt
+    // directly (which would make the simulation frame-rate dependent).
+    let mut world = PhysicsWorld::new();
+    world.set_gravity(Vector3::zero());
+    world.set_fixed_delta_time(0.1);
+
+    let mut body = dynamic_aabb(Vector3::zero(), 1.0);
+    body.velocity = Vector3::new(1.0, 0.0, 0.0);
+    body.collision_groups = CollisionGroups::none();
+    world.add_body(body);
+
+    // 0.25s of real time at a 0.1s fixed step should run exactly 2 fixed steps
+    // (0.2s worth of motion), leaving 0.05s banked in the accumulator.
+    world.advance(0.25);
+
+    let moved = world.bodies()[0].position.x;
+    assert!((moved - 0.2).abs() < 1e-4, "advance didn't run exactly 2 fixed steps worth of motion, moved = {}", moved);
+    assert!((world.interpolation_alpha() - 0.5).abs() < 1e-4, "leftover accumulator time wasn't preserved, alpha = {}", world.interpolation_alpha());
+}